@@ -0,0 +1,113 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "ink-as-dependency")]
+pub use crate::azero_id_domain_registry::AzeroIdDomainRegistryRef;
+
+// Domain registry that looks domains up in an AZERO.ID registry, so that it can be set as the AZGroups domain registry.
+// AZERO.ID has a registry per top level domain, which stores names without the top level domain.
+#[ink::contract]
+mod azero_id_domain_registry {
+    use az_groups::traits::DomainRegistry;
+    use ink::{
+        env::call::{build_call, ExecutionInput, Selector},
+        prelude::{format, string::String},
+    };
+
+    // The registry's get_owner returns Result<AccountId, Error>.
+    // Only the owner is needed, so any error is read as the name not being registered.
+    struct RegistryOwner(Option<AccountId>);
+    impl scale::Decode for RegistryOwner {
+        fn decode<I: scale::Input>(input: &mut I) -> Result<Self, scale::Error> {
+            match input.read_byte()? {
+                0 => Ok(Self(Some(AccountId::decode(input)?))),
+                _ => Ok(Self(None)),
+            }
+        }
+    }
+
+    // registry: The AZERO.ID registry for the top level domain
+    // tld: The top level domain without the dot e.g. azero
+    #[ink(storage)]
+    pub struct AzeroIdDomainRegistry {
+        registry: AccountId,
+        tld: String,
+    }
+    impl AzeroIdDomainRegistry {
+        #[ink(constructor)]
+        pub fn new(registry: AccountId, tld: String) -> Self {
+            Self {
+                registry,
+                tld: tld.to_lowercase(),
+            }
+        }
+
+        // wave.azero => wave. Other top level domains and subdomains aren't in the registry.
+        fn registry_name(&self, domain: &str) -> Option<String> {
+            let name: &str = domain.strip_suffix(&format!(".{}", self.tld))?;
+            if name.is_empty() || name.contains('.') {
+                return None;
+            }
+
+            Some(name.into())
+        }
+    }
+
+    impl DomainRegistry for AzeroIdDomainRegistry {
+        #[ink(message)]
+        fn get_owner(&self, domain: String) -> Option<AccountId> {
+            let name: String = self.registry_name(&domain)?;
+            const GET_OWNER_SELECTOR: [u8; 4] = ink::selector_bytes!("get_owner");
+            build_call::<Environment>()
+                .call(self.registry)
+                .exec_input(ExecutionInput::new(Selector::new(GET_OWNER_SELECTOR)).push_arg(name))
+                .returns::<RegistryOwner>()
+                .try_invoke()
+                .ok()?
+                .ok()?
+                .0
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use scale::{Decode, Encode};
+
+        #[ink::test]
+        fn test_registry_name() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let azero_id_domain_registry =
+                AzeroIdDomainRegistry::new(accounts.django, "AZERO".into());
+            // when domain is in the registry's top level domain
+            // * it returns the name
+            assert_eq!(
+                azero_id_domain_registry.registry_name("wave.azero"),
+                Some("wave".into())
+            );
+            // when domain is in a different top level domain
+            // * it returns None
+            assert_eq!(azero_id_domain_registry.registry_name("wave.tzero"), None);
+            assert_eq!(azero_id_domain_registry.registry_name("waveazero"), None);
+            // when domain is a subdomain or has no name
+            // * it returns None
+            assert_eq!(azero_id_domain_registry.registry_name("a.wave.azero"), None);
+            assert_eq!(azero_id_domain_registry.registry_name(".azero"), None);
+        }
+
+        #[ink::test]
+        fn test_registry_owner_decode() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            // when the registry returns the owner
+            // * it returns the owner
+            let encoded: Vec<u8> = Ok::<AccountId, u8>(accounts.bob).encode();
+            assert_eq!(
+                RegistryOwner::decode(&mut &encoded[..]).unwrap().0,
+                Some(accounts.bob)
+            );
+            // when the registry returns an error
+            // * it returns None
+            let encoded: Vec<u8> = Err::<AccountId, u8>(3).encode();
+            assert_eq!(RegistryOwner::decode(&mut &encoded[..]).unwrap().0, None);
+        }
+    }
+}
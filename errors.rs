@@ -3,6 +3,7 @@ use ink::{
     prelude::{format, string::String},
     LangError,
 };
+// Consumers decode these by index, so new variants go at the end.
 #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum AZGroupsError {
@@ -13,6 +14,7 @@ pub enum AZGroupsError {
     NotFound(String),
     Unauthorised,
     UnprocessableEntity(String),
+    GroupUnverified,
 }
 impl From<InkEnvError> for AZGroupsError {
     fn from(e: InkEnvError) -> Self {
@@ -1,15 +1,86 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod errors;
+#[cfg(feature = "mock")]
+pub mod mock;
+mod names;
+pub mod traits;
+
+#[cfg(feature = "ink-as-dependency")]
+pub use crate::{az_groups::AZGroupsRef, traits::AZGroupsInterface};
+pub use crate::{
+    az_groups::{
+        Election, ElectionCandidate, Group, GroupGovernance, GroupImpeachment, GroupJoinCondition,
+        GroupMetadata, GroupNameChange, GroupNameCharset, GroupNameLookup, GroupNameRules,
+        GroupRecovery, GroupRole, GroupSocial, GroupUser, GroupUserProfile, GroupVerification,
+        JoinConditionPolicy, PendingOperation, PendingOperationAction, PendingPromotion, Petition,
+        PetitionAction, PetitionStatus, Poll, Proposal, ProposalAction, ProposalStatus, Role,
+        SuperAdminClaim, CAPABILITY_APPROVE_APPLICANTS, CAPABILITY_BAN, CAPABILITY_KICK,
+        CAPABILITY_MANAGE_ROLES, CAPABILITY_UPDATE_GROUP, CAPABILITY_UPDATE_METADATA,
+        CAPABILITY_UPDATE_WEIGHTS,
+    },
+    errors::AZGroupsError,
+};
 
 #[ink::contract]
 mod az_groups {
-    use crate::errors::AZGroupsError;
+    use crate::{errors::AZGroupsError, names, traits::AZGroupsInterface};
     use ink::{
-        prelude::string::{String, ToString},
+        env::call::{build_call, ExecutionInput, Selector},
+        prelude::{
+            format,
+            string::{String, ToString},
+            vec::Vec,
+        },
         storage::Mapping,
     };
 
+    // === CONSTANTS ===
+    const ELECTION_CANDIDATES_LIMIT: usize = 20;
+    const GROUP_DESCRIPTION_LENGTH_LIMIT: usize = 500;
+    const GROUP_LOGO_CID_LENGTH_LIMIT: usize = 100;
+    const GROUP_OBSERVER_GAS_LIMIT: u64 = 5_000_000_000;
+    const GROUP_OBSERVERS_LIMIT: usize = 5;
+    const GROUP_NAME_HISTORY_LIMIT: usize = 20;
+    const GROUP_ROLES_LIMIT: usize = 10;
+    const GROUP_SLUG_LENGTH_LIMIT: usize = 32;
+    const GROUP_SOCIAL_LENGTH_LIMIT: usize = 64;
+    const GROUP_SOCIALS_LIMIT: usize = 5;
+    const GROUP_TAGS_LIMIT: usize = 5;
+    const GROUP_USER_PROFILE_FIELD_LENGTH_LIMIT: usize = 64;
+    const GROUP_WEBSITE_LENGTH_LIMIT: usize = 200;
+    const PENDING_OPERATIONS_LIMIT: u32 = 10;
+    const PENDING_PROMOTIONS_LIMIT: usize = 20;
+    const POLL_OPTIONS_LIMIT: u8 = 10;
+    const TAG_GROUPS_PAGE_LIMIT: u32 = 50;
+    const TAG_LENGTH_LIMIT: usize = 32;
+
+    // === CAPABILITIES ===
+    // APPROVE_APPLICANTS: Make an applicant a member
+    // BAN: Ban or unban a user
+    // KICK: Remove another user from the group
+    // MANAGE_ROLES: Any other role change
+    // UPDATE_GROUP: Change the group's name, slug and enabled status
+    // UPDATE_METADATA: Change the group's description, website, logo and socials
+    // UPDATE_WEIGHTS: Change the voting weight of another user
+    pub const CAPABILITY_APPROVE_APPLICANTS: u32 = 1 << 0;
+    pub const CAPABILITY_BAN: u32 = 1 << 1;
+    pub const CAPABILITY_KICK: u32 = 1 << 2;
+    pub const CAPABILITY_MANAGE_ROLES: u32 = 1 << 3;
+    pub const CAPABILITY_UPDATE_GROUP: u32 = 1 << 4;
+    pub const CAPABILITY_UPDATE_METADATA: u32 = 1 << 5;
+    pub const CAPABILITY_UPDATE_WEIGHTS: u32 = 1 << 6;
+    const CAPABILITIES_ALL: u32 = CAPABILITY_APPROVE_APPLICANTS
+        | CAPABILITY_BAN
+        | CAPABILITY_KICK
+        | CAPABILITY_MANAGE_ROLES
+        | CAPABILITY_UPDATE_GROUP
+        | CAPABILITY_UPDATE_METADATA
+        | CAPABILITY_UPDATE_WEIGHTS;
+
+    // === TYPES ===
+    type Vouchers = Vec<AccountId>;
+
     // === ENUMS ===
     #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
     #[cfg_attr(
@@ -24,7 +95,7 @@ mod az_groups {
         SuperAdmin,
     }
     impl Role {
-        fn to_int(&self) -> u8 {
+        pub(crate) fn to_int(&self) -> u8 {
             match *self {
                 Role::Banned => 0,
                 Role::Applicant => 1,
@@ -33,6 +104,120 @@ mod az_groups {
                 Role::SuperAdmin => 4,
             }
         }
+
+        // The ladder that applies when a group hasn't set its own capabilities for a role
+        pub(crate) fn default_capabilities(&self) -> u32 {
+            match *self {
+                Role::Admin => {
+                    CAPABILITIES_ALL & !CAPABILITY_UPDATE_GROUP & !CAPABILITY_UPDATE_METADATA
+                }
+                Role::SuperAdmin => CAPABILITIES_ALL,
+                _ => 0,
+            }
+        }
+    }
+
+    // AllowApplication: Applicant is created as normal and has to be approved by an admin
+    // AutoApprove: Applicant is created straight away as a member
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum JoinConditionPolicy {
+        AllowApplication,
+        AutoApprove,
+    }
+
+    // Characters allowed in group names on top of spaces and - _ . ' &
+    // Any: No restriction beyond normalization
+    // Letters: Letters, numbers and combining marks from any script
+    // Ascii: ASCII letters and numbers
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum GroupNameCharset {
+        Any,
+        Letters,
+        Ascii,
+    }
+
+    // The change that is made when a proposal passes.
+    // GroupUserDestroy: Kick the user
+    // GroupUserUpdate: Change the role of the user
+    // GroupsUpdate: Change the name and enabled status of the group
+    // TreasurySpend: Transfer from the group's treasury
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum ProposalAction {
+        GroupUserDestroy { user: AccountId },
+        GroupUserUpdate { user: AccountId, role: Role },
+        GroupsUpdate { name: String, enabled: bool },
+        TreasurySpend { to: AccountId, amount: Balance },
+    }
+
+    // The sensitive super admin actions that need confirming when a group has a multisig.
+    // GroupMultisigsUpdate: Change the number of confirmations needed
+    // GroupUserDestroy: Kick a super admin
+    // GroupUserUpdate: Demote a super admin
+    // GroupSlugsUpdate: Change the group's slug
+    // GroupsUpdate: Rename or disable the group
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum PendingOperationAction {
+        GroupMultisigsUpdate { confirmations: u8 },
+        GroupUserDestroy { user: AccountId },
+        GroupUserUpdate { user: AccountId, role: Role },
+        GroupSlugsUpdate { slug: String },
+        GroupsUpdate { name: String, enabled: bool },
+    }
+
+    // Demote: Make the admin or super admin a member
+    // Remove: Kick the admin or super admin from the group
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum PetitionAction {
+        Demote,
+        Remove,
+    }
+
+    // Active: Open for signing
+    // Executed: Enough members signed and the action was carried out
+    // Failed: Enough members signed but the action couldn't be carried out e.g. the target was the last super admin
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum PetitionStatus {
+        Active,
+        Executed,
+        Failed,
+    }
+
+    // Active: Open for voting
+    // Executed: Passed and the action was carried out
+    // Failed: Passed but the action couldn't be carried out e.g. the user had already left
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum ProposalStatus {
+        Active,
+        Executed,
+        Failed,
     }
 
     // === EVENTS ===
@@ -52,721 +237,10764 @@ mod az_groups {
     }
 
     #[ink(event)]
-    pub struct GroupUserCreate {
+    pub struct DomainRegistryUpdate {
+        registry: Option<AccountId>,
+    }
+
+    #[ink(event)]
+    pub struct ElectionClose {
         #[ink(topic)]
-        group_id: u32,
+        id: u32,
         #[ink(topic)]
-        user: AccountId,
-        role: Role,
+        group_id: u32,
+        elected: Vec<AccountId>,
     }
 
     #[ink(event)]
-    pub struct GroupUserDestroy {
+    pub struct ElectionCreate {
         #[ink(topic)]
-        group_id: u32,
+        id: u32,
         #[ink(topic)]
-        user: AccountId,
+        group_id: u32,
+        seats: u8,
+        nominations_end_at: Timestamp,
+        voting_ends_at: Timestamp,
+        term: Timestamp,
     }
 
     #[ink(event)]
-    pub struct GroupUserUpdate {
+    pub struct ElectionNominationCreate {
         #[ink(topic)]
-        group_id: u32,
+        election_id: u32,
         #[ink(topic)]
-        user: AccountId,
-        role: Role,
+        candidate: AccountId,
+        nominator: AccountId,
     }
 
-    // === STRUCTS ===
-    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
-    #[cfg_attr(
-        feature = "std",
-        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
-    )]
-    pub struct Group {
-        id: u32,
-        name: String,
-        enabled: bool,
+    #[ink(event)]
+    pub struct ElectionVoteCreate {
+        #[ink(topic)]
+        election_id: u32,
+        #[ink(topic)]
+        voter: AccountId,
+        candidate: AccountId,
     }
 
-    // 0: Banned
-    // 1: Applicant
-    // 2: Member
-    // 3: Admin
-    // 4: SuperAdmin
-    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
-    #[cfg_attr(
-        feature = "std",
-        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
-    )]
-    pub struct GroupUser {
-        role: Role,
+    #[ink(event)]
+    pub struct GroupGovernanceUpdate {
+        #[ink(topic)]
+        group_id: u32,
+        quorum: u32,
+        threshold: u8,
+        voting_period: Timestamp,
     }
 
-    #[ink(storage)]
-    pub struct AZGroups {
-        groups: Mapping<u32, Group>,
-        group_id_by_name: Mapping<String, u32>,
-        groups_total: u32,
-        group_users: Mapping<(u32, AccountId), GroupUser>,
+    #[ink(event)]
+    pub struct GroupTreasuryDeposit {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        from: AccountId,
+        amount: Balance,
     }
-    impl Default for AZGroups {
-        fn default() -> Self {
-            Self::new()
-        }
+
+    #[ink(event)]
+    pub struct GroupDomainUpdate {
+        #[ink(topic)]
+        group_id: u32,
+        domain: Option<String>,
     }
-    impl AZGroups {
-        #[ink(constructor)]
-        pub fn new() -> Self {
-            Self {
-                groups: Mapping::default(),
-                group_id_by_name: Mapping::default(),
-                groups_total: 0,
-                group_users: Mapping::default(),
-            }
-        }
 
-        #[ink(message)]
-        pub fn group_users_create(&mut self, group_id: u32) -> Result<GroupUser, AZGroupsError> {
-            // check if group exists
-            self.groups_show(group_id)?;
-            // check if group user already exists
-            let user: AccountId = Self::env().caller();
-            if self.group_users.get((group_id, user)).is_some() {
-                return Err(AZGroupsError::UnprocessableEntity(
-                    "Group user has already been taken".to_string(),
-                ));
-            }
+    #[ink(event)]
+    pub struct GroupImpeachmentUpdate {
+        #[ink(topic)]
+        group_id: u32,
+        admin_threshold: u8,
+        super_admin_threshold: u8,
+        window: Timestamp,
+    }
 
-            // Create and set group user
-            let group_user: GroupUser = GroupUser {
-                role: Role::Applicant,
-            };
-            self.group_users.insert((group_id, user), &group_user);
+    #[ink(event)]
+    pub struct GroupJoinConditionDestroy {
+        #[ink(topic)]
+        group_id: u32,
+    }
 
-            // emit event
-            self.env().emit_event(GroupUserCreate {
-                group_id,
-                user,
-                role: group_user.role.clone(),
-            });
+    #[ink(event)]
+    pub struct GroupJoinConditionUpdate {
+        #[ink(topic)]
+        group_id: u32,
+        address: AccountId,
+        policy: JoinConditionPolicy,
+    }
 
-            Ok(group_user)
-        }
+    #[ink(event)]
+    pub struct GroupMetadataUpdate {
+        #[ink(topic)]
+        group_id: u32,
+        metadata: GroupMetadata,
+    }
 
-        // User can leave the group, as long as they aren't a super admin or banned.
-        // Super admins can't kick themselves because there's a chance that the group would be left without one.
-        // The only way a super admin can leave the group is to be kicked by another super admin.
-        // User can be kicked by an admin or super-admin, as long as they are of the same role level or less.
-        #[ink(message)]
-        pub fn group_users_destroy(
-            &mut self,
-            group_id: u32,
-            user: AccountId,
-        ) -> Result<(), AZGroupsError> {
-            let caller: AccountId = Self::env().caller();
-            let caller_group_user: GroupUser = self.group_users_show(group_id, caller)?;
-            let user_group_user: GroupUser = self.group_users_show(group_id, user)?;
-            let caller_group_user_role_as_int: u8 = caller_group_user.role.to_int();
-            if caller == user {
-                if caller_group_user_role_as_int == 4 || caller_group_user_role_as_int == 0 {
-                    return Err(AZGroupsError::Unauthorised);
-                }
-            } else if caller_group_user_role_as_int < 3
-                || caller_group_user_role_as_int < user_group_user.role.to_int()
-            {
-                return Err(AZGroupsError::Unauthorised);
-            }
-            self.group_users.remove((group_id, user));
+    #[ink(event)]
+    pub struct GroupMultisigUpdate {
+        #[ink(topic)]
+        group_id: u32,
+        confirmations: u8,
+    }
 
-            // emit event
-            self.env().emit_event(GroupUserDestroy { group_id, user });
+    #[ink(event)]
+    pub struct GroupNameCooldownUpdate {
+        cooldown: Timestamp,
+    }
 
-            Ok(())
-        }
+    #[ink(event)]
+    pub struct GroupNameRulesUpdate {
+        max_bytes: u32,
+        max_chars: u32,
+        charset: GroupNameCharset,
+    }
 
-        #[ink(message)]
-        pub fn group_users_show(
-            &self,
-            group_id: u32,
-            user: AccountId,
-        ) -> Result<GroupUser, AZGroupsError> {
-            self.group_users
-                .get((group_id, user))
-                .ok_or(AZGroupsError::NotFound("GroupUser".to_string()))
-        }
+    #[ink(event)]
+    pub struct GroupPromotionDelayUpdate {
+        #[ink(topic)]
+        group_id: u32,
+        delay: Timestamp,
+    }
 
-        #[ink(message)]
-        pub fn group_users_update(
-            &mut self,
-            group_id: u32,
-            user: AccountId,
-            role: Role,
-        ) -> Result<GroupUser, AZGroupsError> {
-            let role_as_int: u8 = role.to_int();
-            let caller: AccountId = Self::env().caller();
-            if caller == user {
-                return Err(AZGroupsError::Unauthorised);
-            }
-            let caller_group_user: GroupUser = self.group_users_show(group_id, caller)?;
-            let caller_group_user_as_int: u8 = caller_group_user.role.to_int();
-            // Only an admin can make changes
-            if caller_group_user_as_int < 3 {
-                return Err(AZGroupsError::Unauthorised);
-            }
-            let mut user_group_user: GroupUser = self.group_users_show(group_id, user)?;
-            if caller_group_user_as_int < user_group_user.role.to_int() {
-                return Err(AZGroupsError::Unauthorised);
-            }
-            if role_as_int > caller_group_user_as_int {
-                return Err(AZGroupsError::Unauthorised);
-            }
+    #[ink(event)]
+    pub struct GroupRoleCreate {
+        #[ink(topic)]
+        group_id: u32,
+        rank: u8,
+        name: String,
+        base: Role,
+    }
 
-            user_group_user.role = role.clone();
-            self.group_users.insert((group_id, user), &user_group_user);
+    #[ink(event)]
+    pub struct GroupRoleDestroy {
+        #[ink(topic)]
+        group_id: u32,
+        rank: u8,
+    }
 
-            // emit event
-            self.env().emit_event(GroupUserUpdate {
-                group_id,
-                user,
-                role,
-            });
+    #[ink(event)]
+    pub struct GroupRoleCapabilitiesUpdate {
+        #[ink(topic)]
+        group_id: u32,
+        role: Role,
+        capabilities: u32,
+    }
+
+    #[ink(event)]
+    pub struct GroupRecoveryUpdate {
+        #[ink(topic)]
+        group_id: u32,
+        inactivity_period: Timestamp,
+        challenge_period: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct GroupSlugUpdate {
+        #[ink(topic)]
+        group_id: u32,
+        slug: String,
+    }
+
+    #[ink(event)]
+    pub struct GroupTagsUpdate {
+        #[ink(topic)]
+        group_id: u32,
+        tags: Vec<String>,
+    }
+
+    #[ink(event)]
+    pub struct GroupVerificationCreate {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        verifier: AccountId,
+        evidence_hash: Hash,
+        expires_at: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct GroupVerificationDestroy {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        verifier: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct GroupVouchThresholdUpdate {
+        #[ink(topic)]
+        group_id: u32,
+        threshold: u8,
+    }
+
+    #[ink(event)]
+    pub struct GroupWeightLimitUpdate {
+        #[ink(topic)]
+        group_id: u32,
+        limit: u128,
+    }
+
+    #[ink(event)]
+    pub struct GroupObserverCreate {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        observer: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct GroupObserverDestroy {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        observer: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct GroupObserverFailure {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        observer: AccountId,
+        selector: [u8; 4],
+    }
+
+    #[ink(event)]
+    pub struct GroupUserCapabilitiesUpdate {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        user: AccountId,
+        capabilities: u32,
+    }
+
+    #[ink(event)]
+    pub struct GroupUserCreate {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        user: AccountId,
+        role: Role,
+    }
+
+    #[ink(event)]
+    pub struct GroupUserDestroy {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        user: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct GroupUserUpdate {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        user: AccountId,
+        role: Role,
+    }
+
+    #[ink(event)]
+    pub struct GroupUserWeightUpdate {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        user: AccountId,
+        weight: u128,
+    }
+
+    #[ink(event)]
+    pub struct GroupUserProfileUpdate {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        user: AccountId,
+        profile: GroupUserProfile,
+    }
+
+    #[ink(event)]
+    pub struct GroupUserRoleUpdate {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        user: AccountId,
+        rank: Option<u8>,
+    }
+
+    #[ink(event)]
+    pub struct GroupUserTermDestroy {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        user: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct PendingOperationConfirm {
+        #[ink(topic)]
+        id: u32,
+        #[ink(topic)]
+        confirmer: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct PendingOperationCreate {
+        #[ink(topic)]
+        id: u32,
+        #[ink(topic)]
+        group_id: u32,
+        action: PendingOperationAction,
+    }
+
+    #[ink(event)]
+    pub struct PendingOperationExecute {
+        #[ink(topic)]
+        id: u32,
+        #[ink(topic)]
+        group_id: u32,
+    }
+
+    #[ink(event)]
+    pub struct PendingOperationRevoke {
+        #[ink(topic)]
+        id: u32,
+        #[ink(topic)]
+        confirmer: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct PendingPromotionCreate {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        user: AccountId,
+        role: Role,
+        promoter: AccountId,
+        executable_at: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct PendingPromotionDestroy {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        user: AccountId,
+        vetoer: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct PendingPromotionExecute {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        user: AccountId,
+        role: Role,
+    }
+
+    #[ink(event)]
+    pub struct PetitionCreate {
+        #[ink(topic)]
+        id: u32,
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        target: AccountId,
+        action: PetitionAction,
+    }
+
+    #[ink(event)]
+    pub struct PetitionExecute {
+        #[ink(topic)]
+        id: u32,
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        target: AccountId,
+        status: PetitionStatus,
+    }
+
+    #[ink(event)]
+    pub struct PetitionSignatureCreate {
+        #[ink(topic)]
+        petition_id: u32,
+        #[ink(topic)]
+        signer: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct PollCreate {
+        #[ink(topic)]
+        id: u32,
+        #[ink(topic)]
+        group_id: u32,
+        question_hash: Hash,
+        options: u8,
+        opens_at: Timestamp,
+        closes_at: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct PollVoteCreate {
+        #[ink(topic)]
+        poll_id: u32,
+        #[ink(topic)]
+        voter: AccountId,
+        option: u8,
+    }
+
+    #[ink(event)]
+    pub struct ProposalCreate {
+        #[ink(topic)]
+        id: u32,
+        #[ink(topic)]
+        group_id: u32,
+        proposer: AccountId,
+        action: ProposalAction,
+    }
+
+    #[ink(event)]
+    pub struct ProposalExecute {
+        #[ink(topic)]
+        id: u32,
+        #[ink(topic)]
+        group_id: u32,
+        status: ProposalStatus,
+    }
+
+    #[ink(event)]
+    pub struct ProposalVoteCreate {
+        #[ink(topic)]
+        proposal_id: u32,
+        #[ink(topic)]
+        voter: AccountId,
+        support: bool,
+    }
+
+    #[ink(event)]
+    pub struct ReservedNameCreate {
+        name: String,
+    }
+
+    #[ink(event)]
+    pub struct ReservedNameDestroy {
+        name: String,
+    }
+
+    #[ink(event)]
+    pub struct SuperAdminClaimCreate {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        claimant: AccountId,
+        challenge_ends_at: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct SuperAdminClaimDestroy {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        claimant: AccountId,
+        canceller: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct SuperAdminClaimExecute {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        claimant: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct SuperAdminClaimSupportCreate {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        supporter: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct TagCreate {
+        tag: String,
+    }
+
+    #[ink(event)]
+    pub struct TagDestroy {
+        tag: String,
+    }
+
+    #[ink(event)]
+    pub struct VerifierCreate {
+        verifier: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct VerifierDestroy {
+        verifier: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct VouchCreate {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        applicant: AccountId,
+        voucher: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct VouchDestroy {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        applicant: AccountId,
+        voucher: AccountId,
+    }
+
+    // === STRUCTS ===
+    // Members can nominate candidates until nominations_end_at.
+    // Members can then vote until voting_ends_at.
+    // The candidates with the most votes fill the seats as admins for the term.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Election {
+        pub id: u32,
+        pub group_id: u32,
+        pub seats: u8,
+        pub nominations_end_at: Timestamp,
+        pub voting_ends_at: Timestamp,
+        pub term: Timestamp,
+        pub candidates: Vec<ElectionCandidate>,
+        pub elected: Vec<AccountId>,
+        pub closed: bool,
+    }
+
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct ElectionCandidate {
+        pub user: AccountId,
+        pub votes: u32,
+    }
+
+    // domain: Domain verified as owned through the domain registry when it was linked
+    // verification: Attestation from a verifier. Only returned while it hasn't expired and the verifier is still registered.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Group {
+        pub id: u32,
+        pub name: String,
+        pub enabled: bool,
+        pub domain: Option<String>,
+        pub verification: Option<GroupVerification>,
+    }
+
+    // quorum: Minimum voting weight for a proposal to pass
+    // threshold: Minimum percentage of voting weight in support for a proposal to pass
+    // voting_period: Milliseconds that a proposal is open for voting
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct GroupGovernance {
+        pub quorum: u32,
+        pub threshold: u8,
+        pub voting_period: Timestamp,
+    }
+
+    // admin_threshold: Percentage of members that need to sign a petition against an admin
+    // super_admin_threshold: Percentage of members that need to sign a petition against a super admin.
+    // 0 means that super admins can't be petitioned against.
+    // window: Milliseconds that a petition is open for signing
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct GroupImpeachment {
+        pub admin_threshold: u8,
+        pub super_admin_threshold: u8,
+        pub window: Timestamp,
+    }
+
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct GroupJoinCondition {
+        pub address: AccountId,
+        pub policy: JoinConditionPolicy,
+    }
+
+    // Kept apart from Group so that validate_membership doesn't load it.
+    // logo_cid: IPFS CID of the logo
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq, Default)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct GroupMetadata {
+        pub description: Option<String>,
+        pub website: Option<String>,
+        pub logo_cid: Option<String>,
+        pub socials: Vec<GroupSocial>,
+    }
+
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct GroupNameChange {
+        pub name: String,
+        pub replaced_at: Timestamp,
+    }
+
+    // redirected: The name was one of the group's previous names
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct GroupNameLookup {
+        pub group: Group,
+        pub redirected: bool,
+    }
+
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct GroupNameRedirect {
+        pub group_id: u32,
+        pub expires_at: Timestamp,
+    }
+
+    // Limits are checked against the formatted name
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct GroupNameRules {
+        pub max_bytes: u32,
+        pub max_chars: u32,
+        pub charset: GroupNameCharset,
+    }
+
+    // inactivity_period: Milliseconds without super admin activity before admins can claim super admin
+    // challenge_period: Milliseconds that a super admin has to cancel a claim
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct GroupRecovery {
+        pub inactivity_period: Timestamp,
+        pub challenge_period: Timestamp,
+    }
+
+    // A custom label for members or admins of a group e.g. "Core Contributor".
+    // rank: Orders the group's roles and identifies the role within the group
+    // base: The built in role whose rules apply to users with this role
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct GroupRole {
+        pub rank: u8,
+        pub name: String,
+        pub base: Role,
+    }
+
+    // e.g. platform: "x", handle: "@aleph_zero"
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct GroupSocial {
+        pub platform: String,
+        pub handle: String,
+    }
+
+    // 0: Banned
+    // 1: Applicant
+    // 2: Member
+    // 3: Admin
+    // 4: SuperAdmin
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct GroupUser {
+        pub role: Role,
+        pub weight: u128,
+    }
+
+    // display_name: Set by the user
+    // title: Set by an admin or super admin e.g. "Lead Auditor"
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq, Default)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct GroupUserProfile {
+        pub display_name: Option<String>,
+        pub title: Option<String>,
+    }
+
+    // evidence_hash: Hash of the off-chain evidence the verifier relied on
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct GroupVerification {
+        pub verifier: AccountId,
+        pub evidence_hash: Hash,
+        pub expires_at: Timestamp,
+    }
+
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct PendingOperation {
+        pub id: u32,
+        pub group_id: u32,
+        pub proposer: AccountId,
+        pub action: PendingOperationAction,
+        pub confirmations: Vec<AccountId>,
+    }
+
+    // promoter: For an elected admin, the account that closed the election
+    // election_id: Set for an elected admin, whose term starts when the promotion is executed
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct PendingPromotion {
+        pub group_id: u32,
+        pub user: AccountId,
+        pub role: Role,
+        pub promoter: AccountId,
+        pub executable_at: Timestamp,
+        pub election_id: Option<u32>,
+    }
+
+    // threshold is copied from the group's settings when the petition is created
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Petition {
+        pub id: u32,
+        pub group_id: u32,
+        pub target: AccountId,
+        pub action: PetitionAction,
+        pub threshold: u8,
+        pub ends_at: Timestamp,
+        pub signatures: u32,
+        pub status: PetitionStatus,
+    }
+
+    // tallies: Number of votes per option, indexed by option
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Poll {
+        pub id: u32,
+        pub group_id: u32,
+        pub creator: AccountId,
+        pub question_hash: Hash,
+        pub opens_at: Timestamp,
+        pub closes_at: Timestamp,
+        pub tallies: Vec<u32>,
+    }
+
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Proposal {
+        pub id: u32,
+        pub group_id: u32,
+        pub proposer: AccountId,
+        pub description_hash: Hash,
+        pub action: ProposalAction,
+        pub ends_at: Timestamp,
+        pub quorum: u32,
+        pub threshold: u8,
+        pub votes_for: u128,
+        pub votes_against: u128,
+        pub status: ProposalStatus,
+    }
+
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct SuperAdminClaim {
+        pub group_id: u32,
+        pub claimant: AccountId,
+        pub supporters: Vec<AccountId>,
+        pub challenge_ends_at: Timestamp,
+    }
+
+    #[ink(storage)]
+    pub struct AZGroups {
+        groups: Mapping<u32, Group>,
+        group_id_by_name: Mapping<String, u32>,
+        groups_total: u32,
+        group_users: Mapping<(u32, AccountId), GroupUser>,
+        group_join_conditions: Mapping<u32, GroupJoinCondition>,
+        group_observers: Mapping<u32, Vec<AccountId>>,
+        group_governances: Mapping<u32, GroupGovernance>,
+        group_treasuries: Mapping<u32, Balance>,
+        proposals: Mapping<u32, Proposal>,
+        proposals_total: u32,
+        proposal_votes: Mapping<(u32, AccountId), bool>,
+        group_role_totals: Mapping<(u32, u8), u32>,
+        group_multisigs: Mapping<u32, u8>,
+        group_pending_operations: Mapping<u32, Vec<u32>>,
+        pending_operations: Mapping<u32, PendingOperation>,
+        pending_operations_total: u32,
+        group_user_pending_operations_totals: Mapping<(u32, AccountId), u32>,
+        group_promotion_delays: Mapping<u32, Timestamp>,
+        group_pending_promotions: Mapping<u32, Vec<AccountId>>,
+        pending_promotions: Mapping<(u32, AccountId), PendingPromotion>,
+        group_recoveries: Mapping<u32, GroupRecovery>,
+        group_super_admin_activities: Mapping<u32, Timestamp>,
+        super_admin_claims: Mapping<u32, SuperAdminClaim>,
+        elections: Mapping<u32, Election>,
+        elections_total: u32,
+        group_elections: Mapping<u32, u32>,
+        election_votes: Mapping<(u32, AccountId), AccountId>,
+        group_user_terms: Mapping<(u32, AccountId), Timestamp>,
+        group_elected_admins: Mapping<u32, Vec<AccountId>>,
+        group_vouch_thresholds: Mapping<u32, u8>,
+        vouches: Mapping<(u32, AccountId), Vouchers>,
+        group_impeachments: Mapping<u32, GroupImpeachment>,
+        petitions: Mapping<u32, Petition>,
+        petitions_total: u32,
+        petition_signatures: Mapping<(u32, AccountId), ()>,
+        group_weight_limits: Mapping<u32, u128>,
+        group_weight_totals: Mapping<u32, u128>,
+        polls: Mapping<u32, Poll>,
+        polls_total: u32,
+        poll_votes: Mapping<(u32, AccountId), u8>,
+        group_role_capabilities: Mapping<(u32, u8), u32>,
+        group_user_capabilities: Mapping<(u32, AccountId), u32>,
+        group_roles: Mapping<u32, Vec<GroupRole>>,
+        group_role_holders: Mapping<(u32, u8), u32>,
+        group_user_roles: Mapping<(u32, AccountId), u8>,
+        owner: AccountId,
+        group_name_rules: GroupNameRules,
+        reserved_names: Mapping<String, String>,
+        group_name_cooldown: Timestamp,
+        group_name_histories: Mapping<u32, Vec<GroupNameChange>>,
+        group_name_redirects: Mapping<String, GroupNameRedirect>,
+        group_slugs: Mapping<u32, String>,
+        group_id_by_slug: Mapping<String, u32>,
+        domain_registry: Option<AccountId>,
+        group_id_by_domain: Mapping<String, u32>,
+        verifiers: Mapping<AccountId, ()>,
+        group_metadata: Mapping<u32, GroupMetadata>,
+        group_user_profiles: Mapping<(u32, AccountId), GroupUserProfile>,
+        tags: Mapping<String, ()>,
+        group_tags: Mapping<u32, Vec<String>>,
+        tag_group_totals: Mapping<String, u32>,
+        tag_groups: Mapping<(String, u32), u32>,
+        tag_group_positions: Mapping<(String, u32), u32>,
+    }
+    impl Default for AZGroups {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    impl AZGroups {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {
+                groups: Mapping::default(),
+                group_id_by_name: Mapping::default(),
+                groups_total: 0,
+                group_users: Mapping::default(),
+                group_join_conditions: Mapping::default(),
+                group_observers: Mapping::default(),
+                group_governances: Mapping::default(),
+                group_treasuries: Mapping::default(),
+                proposals: Mapping::default(),
+                proposals_total: 0,
+                proposal_votes: Mapping::default(),
+                group_role_totals: Mapping::default(),
+                group_multisigs: Mapping::default(),
+                group_pending_operations: Mapping::default(),
+                pending_operations: Mapping::default(),
+                pending_operations_total: 0,
+                group_user_pending_operations_totals: Mapping::default(),
+                group_promotion_delays: Mapping::default(),
+                group_pending_promotions: Mapping::default(),
+                pending_promotions: Mapping::default(),
+                group_recoveries: Mapping::default(),
+                group_super_admin_activities: Mapping::default(),
+                super_admin_claims: Mapping::default(),
+                elections: Mapping::default(),
+                elections_total: 0,
+                group_elections: Mapping::default(),
+                election_votes: Mapping::default(),
+                group_user_terms: Mapping::default(),
+                group_elected_admins: Mapping::default(),
+                group_vouch_thresholds: Mapping::default(),
+                vouches: Mapping::default(),
+                group_impeachments: Mapping::default(),
+                petitions: Mapping::default(),
+                petitions_total: 0,
+                petition_signatures: Mapping::default(),
+                group_weight_limits: Mapping::default(),
+                group_weight_totals: Mapping::default(),
+                polls: Mapping::default(),
+                polls_total: 0,
+                poll_votes: Mapping::default(),
+                group_role_capabilities: Mapping::default(),
+                group_user_capabilities: Mapping::default(),
+                group_roles: Mapping::default(),
+                group_role_holders: Mapping::default(),
+                group_user_roles: Mapping::default(),
+                owner: Self::env().caller(),
+                group_name_rules: GroupNameRules {
+                    max_bytes: 128,
+                    max_chars: 64,
+                    charset: GroupNameCharset::Letters,
+                },
+                reserved_names: Mapping::default(),
+                group_name_cooldown: 2_592_000_000,
+                group_name_histories: Mapping::default(),
+                group_name_redirects: Mapping::default(),
+                group_slugs: Mapping::default(),
+                group_id_by_slug: Mapping::default(),
+                domain_registry: None,
+                group_id_by_domain: Mapping::default(),
+                verifiers: Mapping::default(),
+                group_metadata: Mapping::default(),
+                group_user_profiles: Mapping::default(),
+                tags: Mapping::default(),
+                group_tags: Mapping::default(),
+                tag_group_totals: Mapping::default(),
+                tag_groups: Mapping::default(),
+                tag_group_positions: Mapping::default(),
+            }
+        }
+
+        // The apply functions make the change without checking the caller.
+        // They are shared by the messages and by proposals that have passed.
+        fn apply_group_multisigs_update(&mut self, group_id: u32, confirmations: u8) {
+            if confirmations > 1 {
+                self.group_multisigs.insert(group_id, &confirmations);
+            } else {
+                self.group_multisigs.remove(group_id);
+            }
+
+            // emit event
+            self.env().emit_event(GroupMultisigUpdate {
+                group_id,
+                confirmations,
+            });
+        }
+
+        // The old slug is released.
+        fn apply_group_slugs_update(&mut self, group_id: u32, slug: String) {
+            if let Some(old_slug) = self.group_slugs.get(group_id) {
+                self.group_id_by_slug.remove(old_slug);
+            }
+            self.group_slugs.insert(group_id, &slug);
+            self.group_id_by_slug.insert(slug.clone(), &group_id);
+
+            // emit event
+            self.env().emit_event(GroupSlugUpdate { group_id, slug });
+        }
+
+        fn apply_group_users_destroy(&mut self, group_id: u32, user: AccountId) {
+            if let Some(group_user) = self.group_users.get((group_id, user)) {
+                self.group_weight_totals_update(group_id, Some(&group_user), None);
+                self.group_role_totals_update(group_id, Some(group_user.role), None);
+            }
+            self.group_users.remove((group_id, user));
+            self.group_user_capabilities.remove((group_id, user));
+            self.group_user_profiles.remove((group_id, user));
+            self.group_user_roles_remove(group_id, user);
+            self.group_user_terms_remove(group_id, user);
+            self.vouches.remove((group_id, user));
+            self.pending_promotions_remove(group_id, user);
+
+            // emit event
+            self.env().emit_event(GroupUserDestroy { group_id, user });
+
+            // notify observers
+            self.group_observers_notify(
+                group_id,
+                ink::selector_bytes!("GroupObserver::on_group_user_destroy"),
+                (group_id, user),
+            );
+        }
+
+        fn apply_group_users_update(
+            &mut self,
+            group_id: u32,
+            user: AccountId,
+            role: Role,
+        ) -> GroupUser {
+            let previous_group_user: Option<GroupUser> = self.group_users.get((group_id, user));
+            let group_user: GroupUser = GroupUser {
+                role: role.clone(),
+                weight: previous_group_user
+                    .as_ref()
+                    .map_or(1, |group_user| group_user.weight),
+            };
+            self.group_weight_totals_update(
+                group_id,
+                previous_group_user.as_ref(),
+                Some(&group_user),
+            );
+            let previous_role: Option<Role> = previous_group_user.map(|group_user| group_user.role);
+            // A custom role only applies to users with its base role
+            if previous_role.as_ref() != Some(&role) {
+                self.group_user_roles_remove(group_id, user);
+            }
+            self.group_role_totals_update(group_id, previous_role, Some(role.clone()));
+            self.group_users.insert((group_id, user), &group_user);
+            self.group_user_terms_remove(group_id, user);
+            self.vouches.remove((group_id, user));
+            self.pending_promotions_remove(group_id, user);
+
+            // emit event
+            self.env().emit_event(GroupUserUpdate {
+                group_id,
+                user,
+                role: role.clone(),
+            });
+
+            // notify observers
+            self.group_observers_notify(
+                group_id,
+                ink::selector_bytes!("GroupObserver::on_group_user_update"),
+                (group_id, user, role),
+            );
+
+            group_user
+        }
+
+        fn apply_groups_update(
+            &mut self,
+            id: u32,
+            name: String,
+            enabled: bool,
+        ) -> Result<Group, AZGroupsError> {
+            let mut group: Group = self.groups_stored_show(id)?;
+            if group.name != name {
+                let name_formatted = names::format_group_name(name);
+                AZGroups::validate_group_name_presence(name_formatted.clone())?;
+                self.validate_group_name_rules(&name_formatted)?;
+
+                let new_key: String = names::group_name_key(&name_formatted);
+                let old_key: String = names::group_name_key(&group.name);
+                if new_key != old_key {
+                    self.validate_group_name_uniqueness(new_key.clone(), Some(id))?;
+
+                    // redirect old name to the group for the cooldown
+                    self.group_id_by_name.remove(old_key.clone());
+                    self.group_name_redirects.insert(
+                        old_key,
+                        &GroupNameRedirect {
+                            group_id: id,
+                            expires_at: self
+                                .env()
+                                .block_timestamp()
+                                .saturating_add(self.group_name_cooldown),
+                        },
+                    );
+                    self.group_name_redirects.remove(new_key.clone());
+                    self.group_id_by_name.insert(new_key, &id);
+                }
+                if name_formatted != group.name {
+                    let mut group_name_histories: Vec<GroupNameChange> =
+                        self.group_name_histories_index(id);
+                    if group_name_histories.len() >= GROUP_NAME_HISTORY_LIMIT {
+                        group_name_histories.remove(0);
+                    }
+                    group_name_histories.push(GroupNameChange {
+                        name: group.name.clone(),
+                        replaced_at: self.env().block_timestamp(),
+                    });
+                    self.group_name_histories.insert(id, &group_name_histories);
+                }
+                group.name = name_formatted;
+            }
+            let enabled_changed: bool = group.enabled != enabled;
+            group.enabled = enabled;
+            self.groups.insert(id, &group);
+            if enabled_changed {
+                for tag in self.group_tags_index(id) {
+                    if enabled {
+                        self.tag_groups_insert(tag, id);
+                    } else {
+                        self.tag_groups_remove(tag, id);
+                    }
+                }
+            }
+
+            // emit event
+            self.env().emit_event(Update {
+                id,
+                name: group.name.clone(),
+                enabled: group.enabled,
+            });
+
+            // notify observers
+            if enabled_changed {
+                self.group_observers_notify(
+                    id,
+                    ink::selector_bytes!("GroupObserver::on_group_update"),
+                    (id, enabled),
+                );
+            }
+
+            self.groups_show(id)
+        }
+
+        // The group as stored. Unlike groups_show, this keeps a verification that has expired
+        // or whose verifier was removed, so it's what has to be written back when the group changes.
+        fn groups_stored_show(&self, id: u32) -> Result<Group, AZGroupsError> {
+            self.groups
+                .get(id)
+                .ok_or(AZGroupsError::NotFound("Group".to_string()))
+        }
+
+        // Trimmed and lowercased
+        fn format_tag(tag: String) -> String {
+            tag.trim().to_lowercase()
+        }
+
+        // Accents are stripped, ASCII letters and digits are lowercased and everything else becomes a hyphen.
+        // Falls back to "group" when nothing is left, and adds "-<id>" when the slug is taken.
+        // If that is taken too, a counter is added after the id until a free slug is found.
+        fn group_slug_derive(&self, name: &str, group_id: u32) -> String {
+            let mut base: String = name
+                .chars()
+                .filter(|c| !names::is_combining_mark(*c))
+                .map(names::strip_accent)
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() {
+                        c.to_ascii_lowercase()
+                    } else {
+                        '-'
+                    }
+                })
+                .collect::<String>()
+                .split('-')
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<&str>>()
+                .join("-");
+            if base.is_empty() {
+                base = "group".to_string();
+            }
+
+            let truncate = |limit: usize| -> String {
+                base[..base.len().min(limit)]
+                    .trim_end_matches('-')
+                    .to_string()
+            };
+            let slug: String = truncate(GROUP_SLUG_LENGTH_LIMIT);
+            if self.group_id_by_slug.get(slug.clone()).is_none() {
+                return slug;
+            }
+            let mut counter: u32 = 1;
+            loop {
+                let suffix: String = if counter == 1 {
+                    format!("-{}", group_id)
+                } else {
+                    format!("-{}-{}", group_id, counter)
+                };
+                let slug: String = truncate(GROUP_SLUG_LENGTH_LIMIT - suffix.len()) + &suffix;
+                if self.group_id_by_slug.get(slug.clone()).is_none() {
+                    return slug;
+                }
+                counter += 1;
+            }
+        }
+
+        // Lowercase ASCII letters and digits, separated by single hyphens.
+        fn is_slug(value: &str) -> bool {
+            value.split('-').all(|part| {
+                !part.is_empty()
+                    && part
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            })
+        }
+
+        // Callbacks are best effort so that a broken observer can't block membership changes.
+        // Each callback gets a fixed amount of gas so that it can't use up the rest of the call's.
+        // Failures, including running out of gas, are reported through an event instead.
+        fn group_observers_notify<Args: scale::Encode>(
+            &self,
+            group_id: u32,
+            selector: [u8; 4],
+            args: Args,
+        ) {
+            for observer in self.group_observers_index(group_id) {
+                let result = build_call::<Environment>()
+                    .call(observer)
+                    .gas_limit(GROUP_OBSERVER_GAS_LIMIT)
+                    .exec_input(ExecutionInput::new(Selector::new(selector)).push_arg(&args))
+                    .returns::<()>()
+                    .try_invoke();
+                if !matches!(result, Ok(Ok(()))) {
+                    self.env().emit_event(GroupObserverFailure {
+                        group_id,
+                        observer,
+                        selector,
+                    });
+                }
+            }
+        }
+
+        // Elected admins whose term has ended are counted as members, even before the term is destroyed.
+        fn group_role_totals_show(&self, group_id: u32, role: Role) -> u32 {
+            let total: u32 = self
+                .group_role_totals
+                .get((group_id, role.to_int()))
+                .unwrap_or_default();
+            match role {
+                Role::Member => total.saturating_add(self.group_expired_terms_total(group_id)),
+                Role::Admin => total.saturating_sub(self.group_expired_terms_total(group_id)),
+                _ => total,
+            }
+        }
+
+        fn group_expired_terms_total(&self, group_id: u32) -> u32 {
+            let block_timestamp: Timestamp = self.env().block_timestamp();
+            self.group_elected_admins
+                .get(group_id)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|user| {
+                    self.group_user_terms
+                        .get((group_id, *user))
+                        .is_some_and(|term_ends_at| block_timestamp >= term_ends_at)
+                })
+                .count() as u32
+        }
+
+        fn group_members_total(&self, group_id: u32) -> u32 {
+            self.group_role_totals_show(group_id, Role::Member)
+                + self.group_role_totals_show(group_id, Role::Admin)
+                + self.group_role_totals_show(group_id, Role::SuperAdmin)
+        }
+
+        fn group_role_totals_update(
+            &mut self,
+            group_id: u32,
+            from: Option<Role>,
+            to: Option<Role>,
+        ) {
+            if let Some(role) = from {
+                let total: u32 = self
+                    .group_role_totals
+                    .get((group_id, role.to_int()))
+                    .unwrap_or_default();
+                self.group_role_totals
+                    .insert((group_id, role.to_int()), &total.saturating_sub(1));
+            }
+            if let Some(role) = to {
+                let total: u32 = self
+                    .group_role_totals
+                    .get((group_id, role.to_int()))
+                    .unwrap_or_default();
+                self.group_role_totals
+                    .insert((group_id, role.to_int()), &(total + 1));
+            }
+        }
+
+        // Only members, admins and super admins count towards the total weight.
+        fn group_weight_totals_update(
+            &mut self,
+            group_id: u32,
+            from: Option<&GroupUser>,
+            to: Option<&GroupUser>,
+        ) {
+            let mut total: u128 = self.group_weight_totals_show(group_id);
+            if let Some(group_user) = from.filter(|group_user| group_user.role.to_int() >= 2) {
+                total = total.saturating_sub(group_user.weight);
+            }
+            if let Some(group_user) = to.filter(|group_user| group_user.role.to_int() >= 2) {
+                total = total.saturating_add(group_user.weight);
+            }
+            self.group_weight_totals.insert(group_id, &total);
+        }
+
+        // Formatted like group names. None when blank.
+        fn group_user_profile_field_format(
+            value: Option<String>,
+            message: &str,
+        ) -> Result<Option<String>, AZGroupsError> {
+            let value: Option<String> = value
+                .map(names::format_group_name)
+                .filter(|value| !value.is_empty());
+            if value
+                .as_ref()
+                .is_some_and(|value| value.len() > GROUP_USER_PROFILE_FIELD_LENGTH_LIMIT)
+            {
+                return Err(AZGroupsError::UnprocessableEntity(message.to_string()));
+            }
+
+            Ok(value)
+        }
+
+        fn group_user_roles_remove(&mut self, group_id: u32, user: AccountId) {
+            if let Some(rank) = self.group_user_roles.get((group_id, user)) {
+                let holders: u32 = self
+                    .group_role_holders
+                    .get((group_id, rank))
+                    .unwrap_or_default();
+                self.group_role_holders
+                    .insert((group_id, rank), &holders.saturating_sub(1));
+                self.group_user_roles.remove((group_id, user));
+            }
+        }
+
+        fn group_user_terms_insert(
+            &mut self,
+            group_id: u32,
+            user: AccountId,
+            term_ends_at: Timestamp,
+        ) {
+            let mut group_elected_admins: Vec<AccountId> =
+                self.group_elected_admins.get(group_id).unwrap_or_default();
+            if !group_elected_admins.contains(&user) {
+                group_elected_admins.push(user);
+                self.group_elected_admins
+                    .insert(group_id, &group_elected_admins);
+            }
+            self.group_user_terms
+                .insert((group_id, user), &term_ends_at);
+        }
+
+        fn group_user_terms_remove(&mut self, group_id: u32, user: AccountId) {
+            if self.group_user_terms.get((group_id, user)).is_none() {
+                return;
+            }
+
+            let mut group_elected_admins: Vec<AccountId> =
+                self.group_elected_admins.get(group_id).unwrap_or_default();
+            group_elected_admins.retain(|u| *u != user);
+            self.group_elected_admins
+                .insert(group_id, &group_elected_admins);
+            self.group_user_terms.remove((group_id, user));
+        }
+
+        // Tracks when a super admin last acted in a group, for inactivity recovery.
+        fn group_super_admin_activities_record(&mut self, group_id: u32, user: AccountId) {
+            if self.validate_super_admin(group_id, user).is_ok() {
+                self.group_super_admin_activities
+                    .insert(group_id, &self.env().block_timestamp());
+            }
+        }
+
+        fn domain_registry_owner(
+            &self,
+            registry: AccountId,
+            domain: String,
+        ) -> Result<Option<AccountId>, AZGroupsError> {
+            const GET_OWNER_SELECTOR: [u8; 4] = ink::selector_bytes!("DomainRegistry::get_owner");
+            Ok(build_call::<Environment>()
+                .call(registry)
+                .exec_input(ExecutionInput::new(Selector::new(GET_OWNER_SELECTOR)).push_arg(domain))
+                .returns::<Option<AccountId>>()
+                .try_invoke()??)
+        }
+
+        fn join_condition_check(
+            &self,
+            address: AccountId,
+            group_id: u32,
+            applicant: AccountId,
+        ) -> Result<bool, AZGroupsError> {
+            const CHECK_SELECTOR: [u8; 4] = ink::selector_bytes!("JoinCondition::check");
+            Ok(build_call::<Environment>()
+                .call(address)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(CHECK_SELECTOR))
+                        .push_arg(group_id)
+                        .push_arg(applicant),
+                )
+                .returns::<bool>()
+                .try_invoke()??)
+        }
+
+        // The caller's confirmation is added straight away.
+        // The limit is per super admin so that one super admin can't stop the others from proposing.
+        fn pending_operations_create(
+            &mut self,
+            group_id: u32,
+            caller: AccountId,
+            action: PendingOperationAction,
+        ) -> Result<PendingOperation, AZGroupsError> {
+            let proposer_pending_operations_total: u32 = self
+                .group_user_pending_operations_totals
+                .get((group_id, caller))
+                .unwrap_or_default();
+            if proposer_pending_operations_total >= PENDING_OPERATIONS_LIMIT {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Pending operation limit reached".to_string(),
+                ));
+            }
+            if self.pending_operations_total == u32::MAX {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Pending operation limit reached".to_string(),
+                ));
+            }
+
+            let pending_operation: PendingOperation = PendingOperation {
+                id: self.pending_operations_total,
+                group_id,
+                proposer: caller,
+                action: action.clone(),
+                confirmations: Vec::from([caller]),
+            };
+            self.pending_operations
+                .insert(pending_operation.id, &pending_operation);
+            self.group_user_pending_operations_totals.insert(
+                (group_id, caller),
+                &(proposer_pending_operations_total + 1),
+            );
+            let mut group_pending_operations: Vec<u32> = self
+                .group_pending_operations
+                .get(group_id)
+                .unwrap_or_default();
+            group_pending_operations.push(pending_operation.id);
+            self.group_pending_operations
+                .insert(group_id, &group_pending_operations);
+            self.pending_operations_total += 1;
+
+            // emit event
+            self.env().emit_event(PendingOperationCreate {
+                id: pending_operation.id,
+                group_id,
+                action,
+            });
+
+            Ok(pending_operation)
+        }
+
+        fn pending_operations_execute(
+            &mut self,
+            pending_operation: &PendingOperation,
+        ) -> Result<(), AZGroupsError> {
+            let group_id: u32 = pending_operation.group_id;
+            match pending_operation.action.clone() {
+                PendingOperationAction::GroupMultisigsUpdate { confirmations } => {
+                    self.validate_group_multisig_confirmations(group_id, confirmations)?;
+                    self.apply_group_multisigs_update(group_id, confirmations);
+                }
+                PendingOperationAction::GroupUserDestroy { user } => {
+                    self.group_users_show(group_id, user)?;
+                    self.validate_super_admin_removal(group_id, user)?;
+                    self.apply_group_users_destroy(group_id, user);
+                }
+                PendingOperationAction::GroupUserUpdate { user, role } => {
+                    self.group_users_show(group_id, user)?;
+                    self.validate_super_admin_removal(group_id, user)?;
+                    self.apply_group_users_update(group_id, user, role);
+                }
+                PendingOperationAction::GroupSlugsUpdate { slug } => {
+                    self.validate_group_slug(&slug)?;
+                    self.apply_group_slugs_update(group_id, slug);
+                }
+                PendingOperationAction::GroupsUpdate { name, enabled } => {
+                    self.apply_groups_update(group_id, name, enabled)?;
+                }
+            }
+            self.pending_operations_remove(pending_operation);
+
+            // emit event
+            self.env().emit_event(PendingOperationExecute {
+                id: pending_operation.id,
+                group_id,
+            });
+
+            Ok(())
+        }
+
+        fn pending_operations_remove(&mut self, pending_operation: &PendingOperation) {
+            let mut group_pending_operations: Vec<u32> = self
+                .group_pending_operations
+                .get(pending_operation.group_id)
+                .unwrap_or_default();
+            group_pending_operations.retain(|id| *id != pending_operation.id);
+            self.group_pending_operations
+                .insert(pending_operation.group_id, &group_pending_operations);
+            self.pending_operations.remove(pending_operation.id);
+            let proposer_pending_operations_total: u32 = self
+                .group_user_pending_operations_totals
+                .get((pending_operation.group_id, pending_operation.proposer))
+                .unwrap_or_default();
+            self.group_user_pending_operations_totals.insert(
+                (pending_operation.group_id, pending_operation.proposer),
+                &proposer_pending_operations_total.saturating_sub(1),
+            );
+        }
+
+        // Elected admins replace any other pending promotion of theirs and aren't held back by the limit,
+        // as an election can't have more winners than the candidate limit.
+        fn pending_promotions_create(
+            &mut self,
+            group_id: u32,
+            user: AccountId,
+            role: Role,
+            promoter: AccountId,
+            election_id: Option<u32>,
+        ) -> Result<PendingPromotion, AZGroupsError> {
+            if election_id.is_some() {
+                self.pending_promotions_remove(group_id, user);
+            }
+            if self.pending_promotions.get((group_id, user)).is_some() {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Pending promotion has already been taken".to_string(),
+                ));
+            }
+            let mut group_pending_promotions: Vec<AccountId> = self
+                .group_pending_promotions
+                .get(group_id)
+                .unwrap_or_default();
+            if election_id.is_none() && group_pending_promotions.len() >= PENDING_PROMOTIONS_LIMIT {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Pending promotion limit reached".to_string(),
+                ));
+            }
+
+            let pending_promotion: PendingPromotion = PendingPromotion {
+                group_id,
+                user,
+                role: role.clone(),
+                promoter,
+                executable_at: self
+                    .env()
+                    .block_timestamp()
+                    .saturating_add(self.group_promotion_delays_show(group_id)),
+                election_id,
+            };
+            self.pending_promotions
+                .insert((group_id, user), &pending_promotion);
+            group_pending_promotions.push(user);
+            self.group_pending_promotions
+                .insert(group_id, &group_pending_promotions);
+
+            // emit event
+            self.env().emit_event(PendingPromotionCreate {
+                group_id,
+                user,
+                role,
+                promoter,
+                executable_at: pending_promotion.executable_at,
+            });
+
+            Ok(pending_promotion)
+        }
+
+        fn pending_promotions_remove(&mut self, group_id: u32, user: AccountId) {
+            if self.pending_promotions.get((group_id, user)).is_none() {
+                return;
+            }
+
+            let mut group_pending_promotions: Vec<AccountId> = self
+                .group_pending_promotions
+                .get(group_id)
+                .unwrap_or_default();
+            group_pending_promotions.retain(|u| *u != user);
+            self.group_pending_promotions
+                .insert(group_id, &group_pending_promotions);
+            self.pending_promotions.remove((group_id, user));
+        }
+
+        fn petitions_execute(&mut self, petition: &Petition) -> Result<(), AZGroupsError> {
+            let group_user: GroupUser =
+                self.group_users_show(petition.group_id, petition.target)?;
+            if group_user.role.to_int() < 3 {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Target is no longer an admin".to_string(),
+                ));
+            }
+            self.validate_super_admin_removal(petition.group_id, petition.target)?;
+            match petition.action {
+                PetitionAction::Demote => {
+                    self.apply_group_users_update(petition.group_id, petition.target, Role::Member);
+                }
+                PetitionAction::Remove => {
+                    self.apply_group_users_destroy(petition.group_id, petition.target)
+                }
+            }
+
+            Ok(())
+        }
+
+        // The action is validated again as the group may have changed since the proposal was created.
+        fn proposals_execute(&mut self, proposal: &Proposal) -> Result<(), AZGroupsError> {
+            self.validate_proposal_action(proposal.group_id, &proposal.action)?;
+            match proposal.action.clone() {
+                ProposalAction::GroupUserDestroy { user } => {
+                    self.apply_group_users_destroy(proposal.group_id, user)
+                }
+                ProposalAction::GroupUserUpdate { user, role } => {
+                    self.apply_group_users_update(proposal.group_id, user, role);
+                }
+                ProposalAction::GroupsUpdate { name, enabled } => {
+                    self.apply_groups_update(proposal.group_id, name, enabled)?;
+                }
+                ProposalAction::TreasurySpend { to, amount } => {
+                    let treasury: Balance = self.group_treasuries_show(proposal.group_id);
+                    self.env().transfer(to, amount)?;
+                    self.group_treasuries
+                        .insert(proposal.group_id, &(treasury - amount));
+                }
+            }
+
+            Ok(())
+        }
+
+        // A claim that can no longer be executed, or that could have been, can be replaced or cleared by any admin
+        // so that recovery isn't held up by a claimant that stopped being an admin.
+        fn super_admin_claim_is_stale(&self, super_admin_claim: &SuperAdminClaim) -> bool {
+            self.env().block_timestamp() >= super_admin_claim.challenge_ends_at
+                || self
+                    .validate_admin(super_admin_claim.group_id, super_admin_claim.claimant)
+                    .is_err()
+        }
+
+        // The index only holds enabled groups.
+        // Removing swaps the last group into the removed group's position.
+        fn tag_groups_insert(&mut self, tag: String, group_id: u32) {
+            if self.tag_group_positions.contains((tag.clone(), group_id)) {
+                return;
+            }
+
+            let total: u32 = self.tag_group_totals.get(tag.clone()).unwrap_or_default();
+            self.tag_groups.insert((tag.clone(), total), &group_id);
+            self.tag_group_positions
+                .insert((tag.clone(), group_id), &total);
+            self.tag_group_totals.insert(tag, &(total + 1));
+        }
+
+        fn tag_groups_remove(&mut self, tag: String, group_id: u32) {
+            let Some(position) = self.tag_group_positions.get((tag.clone(), group_id)) else {
+                return;
+            };
+
+            let last_position: u32 = self.tag_group_totals.get(tag.clone()).unwrap_or_default() - 1;
+            if position != last_position {
+                let last_group_id: u32 = self
+                    .tag_groups
+                    .get((tag.clone(), last_position))
+                    .unwrap_or_default();
+                self.tag_groups
+                    .insert((tag.clone(), position), &last_group_id);
+                self.tag_group_positions
+                    .insert((tag.clone(), last_group_id), &position);
+            }
+            self.tag_groups.remove((tag.clone(), last_position));
+            self.tag_group_positions.remove((tag.clone(), group_id));
+            self.tag_group_totals.insert(tag, &last_position);
+        }
+
+        fn validate_admin(
+            &self,
+            group_id: u32,
+            user: AccountId,
+        ) -> Result<GroupUser, AZGroupsError> {
+            let group_user: GroupUser = self.group_users_show(group_id, user)?;
+            if group_user.role != Role::Admin {
+                return Err(AZGroupsError::Unauthorised);
+            }
+
+            Ok(group_user)
+        }
+
+        fn validate_capability(
+            &self,
+            group_id: u32,
+            user: AccountId,
+            capability: u32,
+        ) -> Result<GroupUser, AZGroupsError> {
+            let group_user: GroupUser = self.group_users_show(group_id, user)?;
+            if self.group_user_capabilities_show(group_id, user) & capability == 0 {
+                return Err(AZGroupsError::Unauthorised);
+            }
+
+            Ok(group_user)
+        }
+
+        fn validate_group_multisig_confirmations(
+            &self,
+            group_id: u32,
+            confirmations: u8,
+        ) -> Result<(), AZGroupsError> {
+            if confirmations == 0 {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Confirmations must be greater than zero".to_string(),
+                ));
+            }
+            if u32::from(confirmations) > self.group_role_totals_show(group_id, Role::SuperAdmin) {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Confirmations can't be more than the number of super admins".to_string(),
+                ));
+            }
+
+            Ok(())
+        }
+
+        fn validate_group_metadata(metadata: &GroupMetadata) -> Result<(), AZGroupsError> {
+            for (field, limit, message) in [
+                (
+                    &metadata.description,
+                    GROUP_DESCRIPTION_LENGTH_LIMIT,
+                    "Description is too long",
+                ),
+                (
+                    &metadata.website,
+                    GROUP_WEBSITE_LENGTH_LIMIT,
+                    "Website is too long",
+                ),
+                (
+                    &metadata.logo_cid,
+                    GROUP_LOGO_CID_LENGTH_LIMIT,
+                    "Logo CID is too long",
+                ),
+            ] {
+                if field.as_ref().is_some_and(|value| value.len() > limit) {
+                    return Err(AZGroupsError::UnprocessableEntity(message.to_string()));
+                }
+            }
+            if metadata.socials.len() > GROUP_SOCIALS_LIMIT {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Socials limit reached".to_string(),
+                ));
+            }
+            if metadata.socials.iter().any(|social| {
+                social.platform.len() > GROUP_SOCIAL_LENGTH_LIMIT
+                    || social.handle.len() > GROUP_SOCIAL_LENGTH_LIMIT
+            }) {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Social is too long".to_string(),
+                ));
+            }
+
+            Ok(())
+        }
+
+        fn validate_group_name_presence(name: String) -> Result<(), AZGroupsError> {
+            if name.is_empty() {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Name can't be blank".to_string(),
+                ));
+            };
+
+            Ok(())
+        }
+
+        // Existing names aren't affected when the rules change.
+        fn validate_group_name_rules(&self, name: &str) -> Result<(), AZGroupsError> {
+            let rules: GroupNameRules = self.group_name_rules_show();
+            if name.len() > rules.max_bytes as usize {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Name is too long".to_string(),
+                ));
+            }
+            if name.chars().count() > rules.max_chars as usize {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Name has too many characters".to_string(),
+                ));
+            }
+            let valid_char = |c: char| -> bool {
+                if c == ' ' || "-_.'&".contains(c) {
+                    return true;
+                }
+                match rules.charset {
+                    GroupNameCharset::Any => true,
+                    GroupNameCharset::Letters => c.is_alphanumeric() || names::is_combining_mark(c),
+                    GroupNameCharset::Ascii => c.is_ascii_alphanumeric(),
+                }
+            };
+            if !name.chars().all(valid_char) {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Name contains invalid characters".to_string(),
+                ));
+            }
+            if self
+                .reserved_names
+                .get(names::group_name_key(name))
+                .is_some()
+            {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Name is reserved".to_string(),
+                ));
+            }
+
+            Ok(())
+        }
+
+        // A group's previous names stay taken until their cooldown ends, except by the group itself.
+        fn validate_group_name_uniqueness(
+            &self,
+            key: String,
+            group_id: Option<u32>,
+        ) -> Result<(), AZGroupsError> {
+            let redirected: bool =
+                self.group_name_redirects
+                    .get(key.clone())
+                    .is_some_and(|redirect| {
+                        redirect.expires_at > self.env().block_timestamp()
+                            && Some(redirect.group_id) != group_id
+                    });
+            if redirected || self.group_id_by_name.get(key).is_some() {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Group has already been taken".to_string(),
+                ));
+            }
+
+            Ok(())
+        }
+
+        fn validate_group_slug(&self, slug: &str) -> Result<(), AZGroupsError> {
+            if slug.len() > GROUP_SLUG_LENGTH_LIMIT {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Slug is too long".to_string(),
+                ));
+            }
+            if !AZGroups::is_slug(slug) {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Slug is invalid".to_string(),
+                ));
+            }
+            if self.group_id_by_slug.get(slug).is_some() {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Slug has already been taken".to_string(),
+                ));
+            }
+
+            Ok(())
+        }
+
+        // Unlike validate_membership, this doesn't check that the group is enabled.
+        fn validate_member(
+            &self,
+            group_id: u32,
+            user: AccountId,
+        ) -> Result<GroupUser, AZGroupsError> {
+            let group_user: GroupUser = self.group_users_show(group_id, user)?;
+            if group_user.role.to_int() < 2 {
+                return Err(AZGroupsError::NotAMember);
+            }
+
+            Ok(group_user)
+        }
+
+        // Proposals can't make or remove super admins, so that a group can't be left without one.
+        // They can't make admins either, so that promotions can't skip the promotion delay.
+        fn validate_proposal_action(
+            &self,
+            group_id: u32,
+            action: &ProposalAction,
+        ) -> Result<(), AZGroupsError> {
+            match action {
+                ProposalAction::GroupUserDestroy { user } => {
+                    let group_user: GroupUser = self.group_users_show(group_id, *user)?;
+                    if group_user.role == Role::SuperAdmin {
+                        return Err(AZGroupsError::Unauthorised);
+                    }
+                }
+                ProposalAction::GroupUserUpdate { user, role } => {
+                    let group_user: GroupUser = self.group_users_show(group_id, *user)?;
+                    if group_user.role == Role::SuperAdmin || role.to_int() >= 3 {
+                        return Err(AZGroupsError::Unauthorised);
+                    }
+                }
+                ProposalAction::GroupsUpdate { name, .. } => {
+                    let name_formatted: String = names::format_group_name(name.clone());
+                    AZGroups::validate_group_name_presence(name_formatted.clone())?;
+                    if name_formatted != self.groups_show(group_id)?.name {
+                        self.validate_group_name_rules(&name_formatted)?;
+                    }
+                }
+                ProposalAction::TreasurySpend { amount, .. } => {
+                    if *amount == 0 {
+                        return Err(AZGroupsError::UnprocessableEntity(
+                            "Amount must be greater than zero".to_string(),
+                        ));
+                    }
+                    if *amount > self.group_treasuries_show(group_id) {
+                        return Err(AZGroupsError::UnprocessableEntity(
+                            "Insufficient treasury balance".to_string(),
+                        ));
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        // A group with a multisig must keep enough super admins to confirm operations.
+        fn validate_super_admin_removal(
+            &self,
+            group_id: u32,
+            user: AccountId,
+        ) -> Result<(), AZGroupsError> {
+            if self.group_users_show(group_id, user)?.role != Role::SuperAdmin {
+                return Ok(());
+            }
+            let super_admins_remaining: u32 = self
+                .group_role_totals_show(group_id, Role::SuperAdmin)
+                .saturating_sub(1);
+            if super_admins_remaining < u32::from(self.group_multisigs_show(group_id)) {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Multisig requires more super admins".to_string(),
+                ));
+            }
+
+            Ok(())
+        }
+
+        fn validate_super_admin(
+            &self,
+            group_id: u32,
+            user: AccountId,
+        ) -> Result<GroupUser, AZGroupsError> {
+            let group_user: GroupUser = self.group_users_show(group_id, user)?;
+            if group_user.role != Role::SuperAdmin {
+                return Err(AZGroupsError::Unauthorised);
+            }
+
+            Ok(group_user)
+        }
+    }
+
+    impl AZGroupsInterface for AZGroups {
+        #[ink(message)]
+        fn domain_registries_show(&self) -> Option<AccountId> {
+            self.domain_registry
+        }
+
+        // Owner sets the registry that domains are verified against. It has to implement DomainRegistry.
+        #[ink(message)]
+        fn domain_registries_update(
+            &mut self,
+            registry: Option<AccountId>,
+        ) -> Result<Option<AccountId>, AZGroupsError> {
+            if Self::env().caller() != self.owner {
+                return Err(AZGroupsError::Unauthorised);
+            }
+
+            self.domain_registry = registry;
+
+            // emit event
+            self.env().emit_event(DomainRegistryUpdate { registry });
+
+            Ok(registry)
+        }
+
+        #[ink(message)]
+        fn election_nominations_create(
+            &mut self,
+            election_id: u32,
+            candidate: AccountId,
+        ) -> Result<Election, AZGroupsError> {
+            let mut election: Election = self.elections_show(election_id)?;
+            let caller: AccountId = Self::env().caller();
+            self.validate_member(election.group_id, caller)?;
+            if self.env().block_timestamp() >= election.nominations_end_at {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Nominations have closed".to_string(),
+                ));
+            }
+            if self.group_users_show(election.group_id, candidate)?.role != Role::Member {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Candidate must be a member".to_string(),
+                ));
+            }
+            if election.candidates.iter().any(|c| c.user == candidate) {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Candidate has already been taken".to_string(),
+                ));
+            }
+            if election.candidates.len() >= ELECTION_CANDIDATES_LIMIT {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Candidate limit reached".to_string(),
+                ));
+            }
+
+            election.candidates.push(ElectionCandidate {
+                user: candidate,
+                votes: 0,
+            });
+            self.elections.insert(election_id, &election);
+
+            // emit event
+            self.env().emit_event(ElectionNominationCreate {
+                election_id,
+                candidate,
+                nominator: caller,
+            });
+
+            Ok(election)
+        }
+
+        #[ink(message)]
+        fn election_votes_create(
+            &mut self,
+            election_id: u32,
+            candidate: AccountId,
+        ) -> Result<Election, AZGroupsError> {
+            let mut election: Election = self.elections_show(election_id)?;
+            let caller: AccountId = Self::env().caller();
+            self.validate_member(election.group_id, caller)?;
+            let block_timestamp: Timestamp = self.env().block_timestamp();
+            if block_timestamp < election.nominations_end_at {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Voting has not started".to_string(),
+                ));
+            }
+            if block_timestamp >= election.voting_ends_at {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Voting has ended".to_string(),
+                ));
+            }
+            if self.election_votes.get((election_id, caller)).is_some() {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Vote has already been taken".to_string(),
+                ));
+            }
+            let Some(election_candidate) =
+                election.candidates.iter_mut().find(|c| c.user == candidate)
+            else {
+                return Err(AZGroupsError::NotFound("ElectionCandidate".to_string()));
+            };
+
+            election_candidate.votes += 1;
+            self.elections.insert(election_id, &election);
+            self.election_votes
+                .insert((election_id, caller), &candidate);
+
+            // emit event
+            self.env().emit_event(ElectionVoteCreate {
+                election_id,
+                voter: caller,
+                candidate,
+            });
+
+            Ok(election)
+        }
+
+        // Anyone can close an election once voting has ended.
+        // Candidates with the most votes become admins for the term, ties going to the earlier nomination.
+        // Candidates without votes, or who are no longer members, aren't elected.
+        // When the group has a promotion delay, the elected candidates are queued as pending promotions instead.
+        #[ink(message)]
+        fn elections_close(&mut self, id: u32) -> Result<Election, AZGroupsError> {
+            let mut election: Election = self.elections_show(id)?;
+            if election.closed {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Election has already been closed".to_string(),
+                ));
+            }
+            let block_timestamp: Timestamp = self.env().block_timestamp();
+            if block_timestamp < election.voting_ends_at {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Voting has not ended".to_string(),
+                ));
+            }
+
+            let term_ends_at: Timestamp = block_timestamp.saturating_add(election.term);
+            let mut candidates: Vec<ElectionCandidate> = election.candidates.clone();
+            candidates.sort_by_key(|candidate| core::cmp::Reverse(candidate.votes));
+            for candidate in candidates {
+                if election.elected.len() >= usize::from(election.seats) || candidate.votes == 0 {
+                    break;
+                }
+                let Ok(group_user) = self.group_users_show(election.group_id, candidate.user)
+                else {
+                    continue;
+                };
+                if group_user.role != Role::Member {
+                    continue;
+                }
+
+                if self.group_promotion_delays_show(election.group_id) > 0 {
+                    self.pending_promotions_create(
+                        election.group_id,
+                        candidate.user,
+                        Role::Admin,
+                        Self::env().caller(),
+                        Some(id),
+                    )?;
+                } else {
+                    self.apply_group_users_update(election.group_id, candidate.user, Role::Admin);
+                    self.group_user_terms_insert(election.group_id, candidate.user, term_ends_at);
+                }
+                election.elected.push(candidate.user);
+            }
+            election.closed = true;
+            self.elections.insert(id, &election);
+
+            // emit event
+            self.env().emit_event(ElectionClose {
+                id,
+                group_id: election.group_id,
+                elected: election.elected.clone(),
+            });
+
+            Ok(election)
+        }
+
+        // Only one election can be in progress per group.
+        #[ink(message)]
+        fn elections_create(
+            &mut self,
+            group_id: u32,
+            seats: u8,
+            nomination_period: Timestamp,
+            voting_period: Timestamp,
+            term: Timestamp,
+        ) -> Result<Election, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            if seats == 0 {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Seats must be greater than zero".to_string(),
+                ));
+            }
+            if nomination_period == 0 || voting_period == 0 || term == 0 {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Periods must be greater than zero".to_string(),
+                ));
+            }
+            if let Some(election_id) = self.group_elections.get(group_id) {
+                if !self.elections_show(election_id)?.closed {
+                    return Err(AZGroupsError::UnprocessableEntity(
+                        "Election is already in progress".to_string(),
+                    ));
+                }
+            }
+            if self.elections_total == u32::MAX {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Election limit reached".to_string(),
+                ));
+            }
+
+            let nominations_end_at: Timestamp = self
+                .env()
+                .block_timestamp()
+                .saturating_add(nomination_period);
+            let election: Election = Election {
+                id: self.elections_total,
+                group_id,
+                seats,
+                nominations_end_at,
+                voting_ends_at: nominations_end_at.saturating_add(voting_period),
+                term,
+                candidates: Vec::new(),
+                elected: Vec::new(),
+                closed: false,
+            };
+            self.elections.insert(election.id, &election);
+            self.group_elections.insert(group_id, &election.id);
+            self.elections_total += 1;
+
+            // emit event
+            self.env().emit_event(ElectionCreate {
+                id: election.id,
+                group_id,
+                seats,
+                nominations_end_at: election.nominations_end_at,
+                voting_ends_at: election.voting_ends_at,
+                term,
+            });
+
+            Ok(election)
+        }
+
+        #[ink(message)]
+        fn elections_show(&self, id: u32) -> Result<Election, AZGroupsError> {
+            self.elections
+                .get(id)
+                .ok_or(AZGroupsError::NotFound("Election".to_string()))
+        }
+
+        #[ink(message)]
+        fn group_domains_destroy(&mut self, group_id: u32) -> Result<(), AZGroupsError> {
+            let mut group: Group = self.groups_stored_show(group_id)?;
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            let domain: String = group
+                .domain
+                .take()
+                .ok_or(AZGroupsError::NotFound("GroupDomain".to_string()))?;
+            self.group_id_by_domain.remove(domain);
+            self.groups.insert(group_id, &group);
+
+            // emit event
+            self.env().emit_event(GroupDomainUpdate {
+                group_id,
+                domain: None,
+            });
+
+            Ok(())
+        }
+
+        // owner: The account that has to own the domain in the registry. Defaults to the caller.
+        // It has to be the caller or a super admin of the group, so that a domain can't be linked without its owner.
+        // Ownership is only checked when the domain is linked.
+        #[ink(message)]
+        fn group_domains_update(
+            &mut self,
+            group_id: u32,
+            domain: String,
+            owner: Option<AccountId>,
+        ) -> Result<Group, AZGroupsError> {
+            let mut group: Group = self.groups_stored_show(group_id)?;
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            let domain: String = domain.trim().to_lowercase();
+            if domain.is_empty() {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Domain can't be blank".to_string(),
+                ));
+            }
+            if let Some(domain_group_id) = self.group_id_by_domain.get(domain.clone()) {
+                if domain_group_id != group_id {
+                    return Err(AZGroupsError::UnprocessableEntity(
+                        "Domain has already been taken".to_string(),
+                    ));
+                }
+            }
+            let owner: AccountId = owner.unwrap_or(caller);
+            if owner != caller {
+                self.validate_super_admin(group_id, owner)?;
+            }
+            let registry: AccountId =
+                self.domain_registry
+                    .ok_or(AZGroupsError::UnprocessableEntity(
+                        "Domain registry isn't set".to_string(),
+                    ))?;
+            if self.domain_registry_owner(registry, domain.clone())? != Some(owner) {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Domain isn't owned by the account".to_string(),
+                ));
+            }
+
+            if let Some(old_domain) = group.domain.replace(domain.clone()) {
+                self.group_id_by_domain.remove(old_domain);
+            }
+            self.group_id_by_domain.insert(domain.clone(), &group_id);
+            self.groups.insert(group_id, &group);
+
+            // emit event
+            self.env().emit_event(GroupDomainUpdate {
+                group_id,
+                domain: Some(domain),
+            });
+
+            self.groups_show(group_id)
+        }
+
+        #[ink(message)]
+        fn group_governances_show(&self, group_id: u32) -> Result<GroupGovernance, AZGroupsError> {
+            self.group_governances
+                .get(group_id)
+                .ok_or(AZGroupsError::NotFound("GroupGovernance".to_string()))
+        }
+
+        #[ink(message)]
+        fn group_governances_update(
+            &mut self,
+            group_id: u32,
+            quorum: u32,
+            threshold: u8,
+            voting_period: Timestamp,
+        ) -> Result<GroupGovernance, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            if quorum == 0 {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Quorum must be greater than zero".to_string(),
+                ));
+            }
+            if threshold == 0 || threshold > 100 {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Threshold must be between 1 and 100".to_string(),
+                ));
+            }
+            if voting_period == 0 {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Voting period must be greater than zero".to_string(),
+                ));
+            }
+
+            let group_governance: GroupGovernance = GroupGovernance {
+                quorum,
+                threshold,
+                voting_period,
+            };
+            self.group_governances.insert(group_id, &group_governance);
+
+            // emit event
+            self.env().emit_event(GroupGovernanceUpdate {
+                group_id,
+                quorum,
+                threshold,
+                voting_period,
+            });
+
+            Ok(group_governance)
+        }
+
+        #[ink(message)]
+        fn group_impeachments_show(
+            &self,
+            group_id: u32,
+        ) -> Result<GroupImpeachment, AZGroupsError> {
+            self.group_impeachments
+                .get(group_id)
+                .ok_or(AZGroupsError::NotFound("GroupImpeachment".to_string()))
+        }
+
+        #[ink(message)]
+        fn group_impeachments_update(
+            &mut self,
+            group_id: u32,
+            admin_threshold: u8,
+            super_admin_threshold: u8,
+            window: Timestamp,
+        ) -> Result<GroupImpeachment, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            if admin_threshold == 0 || admin_threshold > 100 {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Admin threshold must be between 1 and 100".to_string(),
+                ));
+            }
+            if super_admin_threshold > 100
+                || (super_admin_threshold > 0 && super_admin_threshold < admin_threshold)
+            {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Super admin threshold must be 0 or between the admin threshold and 100"
+                        .to_string(),
+                ));
+            }
+            if window == 0 {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Window must be greater than zero".to_string(),
+                ));
+            }
+
+            let group_impeachment: GroupImpeachment = GroupImpeachment {
+                admin_threshold,
+                super_admin_threshold,
+                window,
+            };
+            self.group_impeachments.insert(group_id, &group_impeachment);
+
+            // emit event
+            self.env().emit_event(GroupImpeachmentUpdate {
+                group_id,
+                admin_threshold,
+                super_admin_threshold,
+                window,
+            });
+
+            Ok(group_impeachment)
+        }
+
+        #[ink(message)]
+        fn group_join_conditions_destroy(&mut self, group_id: u32) -> Result<(), AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            self.group_join_conditions_show(group_id)?;
+            self.group_join_conditions.remove(group_id);
+
+            // emit event
+            self.env()
+                .emit_event(GroupJoinConditionDestroy { group_id });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn group_join_conditions_show(
+            &self,
+            group_id: u32,
+        ) -> Result<GroupJoinCondition, AZGroupsError> {
+            self.group_join_conditions
+                .get(group_id)
+                .ok_or(AZGroupsError::NotFound("GroupJoinCondition".to_string()))
+        }
+
+        #[ink(message)]
+        fn group_join_conditions_update(
+            &mut self,
+            group_id: u32,
+            address: AccountId,
+            policy: JoinConditionPolicy,
+        ) -> Result<GroupJoinCondition, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+
+            let group_join_condition: GroupJoinCondition = GroupJoinCondition {
+                address,
+                policy: policy.clone(),
+            };
+            self.group_join_conditions
+                .insert(group_id, &group_join_condition);
+
+            // emit event
+            self.env().emit_event(GroupJoinConditionUpdate {
+                group_id,
+                address,
+                policy,
+            });
+
+            Ok(group_join_condition)
+        }
+
+        // Returns empty metadata when the group's metadata hasn't been set.
+        #[ink(message)]
+        fn group_metadata_show(&self, group_id: u32) -> Result<GroupMetadata, AZGroupsError> {
+            self.groups_show(group_id)?;
+
+            Ok(self.group_metadata.get(group_id).unwrap_or_default())
+        }
+
+        // Replaces all of the group's metadata.
+        #[ink(message)]
+        fn group_metadata_update(
+            &mut self,
+            group_id: u32,
+            metadata: GroupMetadata,
+        ) -> Result<GroupMetadata, AZGroupsError> {
+            self.groups_show(group_id)?;
+            let caller: AccountId = Self::env().caller();
+            self.validate_capability(group_id, caller, CAPABILITY_UPDATE_METADATA)?;
+            AZGroups::validate_group_metadata(&metadata)?;
+            self.group_super_admin_activities_record(group_id, caller);
+
+            self.group_metadata.insert(group_id, &metadata);
+
+            // emit event
+            self.env().emit_event(GroupMetadataUpdate {
+                group_id,
+                metadata: metadata.clone(),
+            });
+
+            Ok(metadata)
+        }
+
+        // Returns the number of super admin confirmations needed for sensitive actions.
+        // 1 means that the group doesn't have a multisig.
+        #[ink(message)]
+        fn group_multisigs_show(&self, group_id: u32) -> u8 {
+            self.group_multisigs.get(group_id).unwrap_or(1)
+        }
+
+        // Setting confirmations to 1 turns the multisig off.
+        // When the group already has a multisig, the change has to be confirmed.
+        #[ink(message)]
+        fn group_multisigs_update(
+            &mut self,
+            group_id: u32,
+            confirmations: u8,
+        ) -> Result<u8, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            self.validate_group_multisig_confirmations(group_id, confirmations)?;
+            if self.group_multisigs_show(group_id) > 1 {
+                self.pending_operations_create(
+                    group_id,
+                    caller,
+                    PendingOperationAction::GroupMultisigsUpdate { confirmations },
+                )?;
+                return Ok(self.group_multisigs_show(group_id));
+            }
+
+            self.apply_group_multisigs_update(group_id, confirmations);
+
+            Ok(confirmations)
+        }
+
+        #[ink(message)]
+        fn group_name_cooldowns_show(&self) -> Timestamp {
+            self.group_name_cooldown
+        }
+
+        // Owner sets how long a group's previous names stay reserved for it.
+        // Changes only apply to names replaced afterwards.
+        #[ink(message)]
+        fn group_name_cooldowns_update(
+            &mut self,
+            cooldown: Timestamp,
+        ) -> Result<Timestamp, AZGroupsError> {
+            if Self::env().caller() != self.owner {
+                return Err(AZGroupsError::Unauthorised);
+            }
+
+            self.group_name_cooldown = cooldown;
+
+            // emit event
+            self.env().emit_event(GroupNameCooldownUpdate { cooldown });
+
+            Ok(cooldown)
+        }
+
+        // The group's previous names, oldest first. Only the latest 20 are kept.
+        #[ink(message)]
+        fn group_name_histories_index(&self, group_id: u32) -> Vec<GroupNameChange> {
+            self.group_name_histories.get(group_id).unwrap_or_default()
+        }
+
+        #[ink(message)]
+        fn group_name_rules_show(&self) -> GroupNameRules {
+            self.group_name_rules.clone()
+        }
+
+        #[ink(message)]
+        fn group_name_rules_update(
+            &mut self,
+            max_bytes: u32,
+            max_chars: u32,
+            charset: GroupNameCharset,
+        ) -> Result<GroupNameRules, AZGroupsError> {
+            if Self::env().caller() != self.owner {
+                return Err(AZGroupsError::Unauthorised);
+            }
+            if max_bytes == 0 || max_chars == 0 {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Limits must be greater than zero".to_string(),
+                ));
+            }
+
+            self.group_name_rules = GroupNameRules {
+                max_bytes,
+                max_chars,
+                charset: charset.clone(),
+            };
+
+            // emit event
+            self.env().emit_event(GroupNameRulesUpdate {
+                max_bytes,
+                max_chars,
+                charset,
+            });
+
+            Ok(self.group_name_rules.clone())
+        }
+
+        #[ink(message)]
+        fn group_observers_create(
+            &mut self,
+            group_id: u32,
+            observer: AccountId,
+        ) -> Result<Vec<AccountId>, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            let mut group_observers: Vec<AccountId> = self.group_observers_index(group_id);
+            if group_observers.contains(&observer) {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Group observer has already been taken".to_string(),
+                ));
+            }
+            if group_observers.len() >= GROUP_OBSERVERS_LIMIT {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Group observer limit reached".to_string(),
+                ));
+            }
+
+            group_observers.push(observer);
+            self.group_observers.insert(group_id, &group_observers);
+
+            // emit event
+            self.env()
+                .emit_event(GroupObserverCreate { group_id, observer });
+
+            Ok(group_observers)
+        }
+
+        #[ink(message)]
+        fn group_observers_destroy(
+            &mut self,
+            group_id: u32,
+            observer: AccountId,
+        ) -> Result<Vec<AccountId>, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            let mut group_observers: Vec<AccountId> = self.group_observers_index(group_id);
+            let Some(index) = group_observers.iter().position(|o| *o == observer) else {
+                return Err(AZGroupsError::NotFound("GroupObserver".to_string()));
+            };
+
+            group_observers.remove(index);
+            self.group_observers.insert(group_id, &group_observers);
+
+            // emit event
+            self.env()
+                .emit_event(GroupObserverDestroy { group_id, observer });
+
+            Ok(group_observers)
+        }
+
+        #[ink(message)]
+        fn group_observers_index(&self, group_id: u32) -> Vec<AccountId> {
+            self.group_observers.get(group_id).unwrap_or_default()
+        }
+
+        // Returns the milliseconds that promotions to admin or super admin are delayed by.
+        #[ink(message)]
+        fn group_promotion_delays_show(&self, group_id: u32) -> Timestamp {
+            self.group_promotion_delays
+                .get(group_id)
+                .unwrap_or_default()
+        }
+
+        #[ink(message)]
+        fn group_promotion_delays_update(
+            &mut self,
+            group_id: u32,
+            delay: Timestamp,
+        ) -> Result<Timestamp, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+
+            self.group_promotion_delays.insert(group_id, &delay);
+
+            // emit event
+            self.env()
+                .emit_event(GroupPromotionDelayUpdate { group_id, delay });
+
+            Ok(delay)
+        }
+
+        #[ink(message)]
+        fn group_recoveries_show(&self, group_id: u32) -> Result<GroupRecovery, AZGroupsError> {
+            self.group_recoveries
+                .get(group_id)
+                .ok_or(AZGroupsError::NotFound("GroupRecovery".to_string()))
+        }
+
+        #[ink(message)]
+        fn group_recoveries_update(
+            &mut self,
+            group_id: u32,
+            inactivity_period: Timestamp,
+            challenge_period: Timestamp,
+        ) -> Result<GroupRecovery, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            if inactivity_period == 0 {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Inactivity period must be greater than zero".to_string(),
+                ));
+            }
+
+            let group_recovery: GroupRecovery = GroupRecovery {
+                inactivity_period,
+                challenge_period,
+            };
+            self.group_recoveries.insert(group_id, &group_recovery);
+
+            // emit event
+            self.env().emit_event(GroupRecoveryUpdate {
+                group_id,
+                inactivity_period,
+                challenge_period,
+            });
+
+            Ok(group_recovery)
+        }
+
+        #[ink(message)]
+        fn group_role_capabilities_show(&self, group_id: u32, role: Role) -> u32 {
+            self.group_role_capabilities
+                .get((group_id, role.to_int()))
+                .unwrap_or(role.default_capabilities())
+        }
+
+        // Super admin can change what members and admins are allowed to do.
+        // Super admins always have every capability.
+        #[ink(message)]
+        fn group_role_capabilities_update(
+            &mut self,
+            group_id: u32,
+            role: Role,
+            capabilities: u32,
+        ) -> Result<u32, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            if role != Role::Member && role != Role::Admin {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Role must be member or admin".to_string(),
+                ));
+            }
+            if capabilities & !CAPABILITIES_ALL != 0 {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Capabilities are invalid".to_string(),
+                ));
+            }
+
+            self.group_role_capabilities
+                .insert((group_id, role.to_int()), &capabilities);
+
+            // emit event
+            self.env().emit_event(GroupRoleCapabilitiesUpdate {
+                group_id,
+                role,
+                capabilities,
+            });
+
+            Ok(capabilities)
+        }
+
+        // Super admin can add custom roles for members and admins.
+        // Roles with a higher rank can't have a lower base role.
+        #[ink(message)]
+        fn group_roles_create(
+            &mut self,
+            group_id: u32,
+            name: String,
+            rank: u8,
+            base: Role,
+        ) -> Result<GroupRole, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            if base != Role::Member && base != Role::Admin {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Base must be member or admin".to_string(),
+                ));
+            }
+            let name: String = name.trim().to_string();
+            if name.is_empty() {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Name can't be blank".to_string(),
+                ));
+            }
+            let mut group_roles: Vec<GroupRole> = self.group_roles_index(group_id);
+            if group_roles.len() >= GROUP_ROLES_LIMIT {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Group role limit reached".to_string(),
+                ));
+            }
+            if group_roles.iter().any(|group_role| group_role.rank == rank) {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Rank has already been taken".to_string(),
+                ));
+            }
+            if group_roles
+                .iter()
+                .any(|group_role| group_role.name.to_lowercase() == name.to_lowercase())
+            {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Name has already been taken".to_string(),
+                ));
+            }
+            if group_roles.iter().any(|group_role| {
+                (group_role.rank < rank && group_role.base.to_int() > base.to_int())
+                    || (group_role.rank > rank && group_role.base.to_int() < base.to_int())
+            }) {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Rank is out of order with the base role".to_string(),
+                ));
+            }
+
+            let group_role: GroupRole = GroupRole {
+                rank,
+                name: name.clone(),
+                base: base.clone(),
+            };
+            group_roles.push(group_role.clone());
+            group_roles.sort_by_key(|group_role| group_role.rank);
+            self.group_roles.insert(group_id, &group_roles);
+
+            // emit event
+            self.env().emit_event(GroupRoleCreate {
+                group_id,
+                rank,
+                name,
+                base,
+            });
+
+            Ok(group_role)
+        }
+
+        #[ink(message)]
+        fn group_roles_destroy(&mut self, group_id: u32, rank: u8) -> Result<(), AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            let mut group_roles: Vec<GroupRole> = self.group_roles_index(group_id);
+            if !group_roles.iter().any(|group_role| group_role.rank == rank) {
+                return Err(AZGroupsError::NotFound("GroupRole".to_string()));
+            }
+            if self
+                .group_role_holders
+                .get((group_id, rank))
+                .unwrap_or_default()
+                > 0
+            {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Group role is in use".to_string(),
+                ));
+            }
+
+            group_roles.retain(|group_role| group_role.rank != rank);
+            self.group_roles.insert(group_id, &group_roles);
+
+            // emit event
+            self.env().emit_event(GroupRoleDestroy { group_id, rank });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn group_roles_index(&self, group_id: u32) -> Vec<GroupRole> {
+            self.group_roles.get(group_id).unwrap_or_default()
+        }
+
+        #[ink(message)]
+        fn group_slugs_show(&self, group_id: u32) -> Result<String, AZGroupsError> {
+            self.group_slugs
+                .get(group_id)
+                .ok_or(AZGroupsError::NotFound("Group".to_string()))
+        }
+
+        // Changing the slug has to be confirmed when the group has a multisig.
+        #[ink(message)]
+        fn group_slugs_update(
+            &mut self,
+            group_id: u32,
+            slug: String,
+        ) -> Result<String, AZGroupsError> {
+            let current_slug: String = self.group_slugs_show(group_id)?;
+            let caller: AccountId = Self::env().caller();
+            self.validate_capability(group_id, caller, CAPABILITY_UPDATE_GROUP)?;
+            self.validate_group_slug(&slug)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            if self.group_multisigs_show(group_id) > 1 {
+                self.pending_operations_create(
+                    group_id,
+                    caller,
+                    PendingOperationAction::GroupSlugsUpdate { slug },
+                )?;
+                return Ok(current_slug);
+            }
+
+            self.apply_group_slugs_update(group_id, slug.clone());
+
+            Ok(slug)
+        }
+
+        // Returns when a super admin last acted in the group.
+        #[ink(message)]
+        fn group_super_admin_activities_show(&self, group_id: u32) -> Timestamp {
+            self.group_super_admin_activities
+                .get(group_id)
+                .unwrap_or_default()
+        }
+
+        // Lets a super admin show that they are still active without making any other change.
+        #[ink(message)]
+        fn group_super_admin_activities_update(
+            &mut self,
+            group_id: u32,
+        ) -> Result<Timestamp, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+
+            Ok(self.group_super_admin_activities_show(group_id))
+        }
+
+        #[ink(message)]
+        fn group_tags_index(&self, group_id: u32) -> Vec<String> {
+            self.group_tags.get(group_id).unwrap_or_default()
+        }
+
+        // Replaces the group's tags. New tags have to be in the vocabulary.
+        #[ink(message)]
+        fn group_tags_update(
+            &mut self,
+            group_id: u32,
+            tags: Vec<String>,
+        ) -> Result<Vec<String>, AZGroupsError> {
+            let group: Group = self.groups_show(group_id)?;
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            if tags.len() > GROUP_TAGS_LIMIT {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Tags limit reached".to_string(),
+                ));
+            }
+            let old_tags: Vec<String> = self.group_tags_index(group_id);
+            let mut new_tags: Vec<String> = Vec::new();
+            for tag in tags {
+                let tag: String = AZGroups::format_tag(tag);
+                if new_tags.contains(&tag) {
+                    return Err(AZGroupsError::UnprocessableEntity(
+                        "Tags must be unique".to_string(),
+                    ));
+                }
+                if !old_tags.contains(&tag) {
+                    self.tags_show(tag.clone())?;
+                }
+                new_tags.push(tag);
+            }
+
+            if group.enabled {
+                for tag in old_tags.iter().filter(|tag| !new_tags.contains(tag)) {
+                    self.tag_groups_remove(tag.clone(), group_id);
+                }
+                for tag in new_tags.iter() {
+                    self.tag_groups_insert(tag.clone(), group_id);
+                }
+            }
+            self.group_tags.insert(group_id, &new_tags);
+
+            // emit event
+            self.env().emit_event(GroupTagsUpdate {
+                group_id,
+                tags: new_tags.clone(),
+            });
+
+            Ok(new_tags)
+        }
+
+        // Anyone can deposit into a group's treasury.
+        // The treasury can only be spent through a proposal.
+        #[ink(message, payable)]
+        fn group_treasuries_deposit(&mut self, group_id: u32) -> Result<Balance, AZGroupsError> {
+            self.groups_show(group_id)?;
+            let amount: Balance = self.env().transferred_value();
+            if amount == 0 {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Amount must be greater than zero".to_string(),
+                ));
+            }
+
+            let treasury: Balance = self.group_treasuries_show(group_id) + amount;
+            self.group_treasuries.insert(group_id, &treasury);
+
+            // emit event
+            self.env().emit_event(GroupTreasuryDeposit {
+                group_id,
+                from: Self::env().caller(),
+                amount,
+            });
+
+            Ok(treasury)
+        }
+
+        #[ink(message)]
+        fn group_treasuries_show(&self, group_id: u32) -> Balance {
+            self.group_treasuries.get(group_id).unwrap_or_default()
+        }
+
+        // Capabilities of the user's role combined with any granted to the user.
+        // Grants only apply while the user is a member, admin or super admin.
+        #[ink(message)]
+        fn group_user_capabilities_show(&self, group_id: u32, user: AccountId) -> u32 {
+            let group_user: GroupUser = match self.group_users_show(group_id, user) {
+                Ok(group_user) => group_user,
+                Err(_) => return 0,
+            };
+            let mut capabilities: u32 =
+                self.group_role_capabilities_show(group_id, group_user.role.clone());
+            if group_user.role.to_int() >= 2 {
+                capabilities |= self
+                    .group_user_capabilities
+                    .get((group_id, user))
+                    .unwrap_or_default();
+            }
+
+            capabilities
+        }
+
+        // Super admin can grant capabilities to a member on top of their role's, e.g. to make a moderator.
+        // Updating replaces the previous grant.
+        #[ink(message)]
+        fn group_user_capabilities_update(
+            &mut self,
+            group_id: u32,
+            user: AccountId,
+            capabilities: u32,
+        ) -> Result<u32, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            self.validate_member(group_id, user)?;
+            if capabilities & !CAPABILITIES_ALL != 0 {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Capabilities are invalid".to_string(),
+                ));
+            }
+
+            if capabilities == 0 {
+                self.group_user_capabilities.remove((group_id, user));
+            } else {
+                self.group_user_capabilities
+                    .insert((group_id, user), &capabilities);
+            }
+
+            // emit event
+            self.env().emit_event(GroupUserCapabilitiesUpdate {
+                group_id,
+                user,
+                capabilities,
+            });
+
+            Ok(capabilities)
+        }
+
+        #[ink(message)]
+        fn group_user_profiles_show(
+            &self,
+            group_id: u32,
+            user: AccountId,
+        ) -> Result<GroupUserProfile, AZGroupsError> {
+            self.group_users_show(group_id, user)?;
+
+            Ok(self
+                .group_user_profiles
+                .get((group_id, user))
+                .unwrap_or_default())
+        }
+
+        // Members set their own display name.
+        #[ink(message)]
+        fn group_user_profiles_update(
+            &mut self,
+            group_id: u32,
+            display_name: Option<String>,
+        ) -> Result<GroupUserProfile, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_member(group_id, caller)?;
+            let display_name: Option<String> = AZGroups::group_user_profile_field_format(
+                display_name,
+                "Display name is too long",
+            )?;
+
+            let mut profile: GroupUserProfile = self.group_user_profiles_show(group_id, caller)?;
+            profile.display_name = display_name;
+            self.group_user_profiles
+                .insert((group_id, caller), &profile);
+
+            // emit event
+            self.env().emit_event(GroupUserProfileUpdate {
+                group_id,
+                user: caller,
+                profile: profile.clone(),
+            });
+
+            Ok(profile)
+        }
+
+        #[ink(message)]
+        fn group_user_roles_show(&self, group_id: u32, user: AccountId) -> Option<GroupRole> {
+            let rank: u8 = self.group_user_roles.get((group_id, user))?;
+            self.group_roles_index(group_id)
+                .into_iter()
+                .find(|group_role| group_role.rank == rank)
+        }
+
+        // Users with the manage roles capability can give a custom role to a user with the same role or less.
+        // The user's role has to match the custom role's base. Changing the user's role removes the custom role.
+        #[ink(message)]
+        fn group_user_roles_update(
+            &mut self,
+            group_id: u32,
+            user: AccountId,
+            rank: Option<u8>,
+        ) -> Result<Option<GroupRole>, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            if caller == user {
+                return Err(AZGroupsError::Unauthorised);
+            }
+            let caller_group_user: GroupUser =
+                self.validate_capability(group_id, caller, CAPABILITY_MANAGE_ROLES)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            let user_group_user: GroupUser = self.group_users_show(group_id, user)?;
+            if caller_group_user.role.to_int() < user_group_user.role.to_int() {
+                return Err(AZGroupsError::Unauthorised);
+            }
+            let group_role: Option<GroupRole> = match rank {
+                Some(rank) => Some(
+                    self.group_roles_index(group_id)
+                        .into_iter()
+                        .find(|group_role| group_role.rank == rank)
+                        .ok_or(AZGroupsError::NotFound("GroupRole".to_string()))?,
+                ),
+                None => None,
+            };
+            if let Some(group_role) = &group_role {
+                if group_role.base != user_group_user.role {
+                    return Err(AZGroupsError::UnprocessableEntity(
+                        "Role must match the group role's base".to_string(),
+                    ));
+                }
+            }
+
+            self.group_user_roles_remove(group_id, user);
+            if let Some(rank) = rank {
+                let holders: u32 = self
+                    .group_role_holders
+                    .get((group_id, rank))
+                    .unwrap_or_default();
+                self.group_role_holders
+                    .insert((group_id, rank), &(holders + 1));
+                self.group_user_roles.insert((group_id, user), &rank);
+            }
+
+            // emit event
+            self.env().emit_event(GroupUserRoleUpdate {
+                group_id,
+                user,
+                rank,
+            });
+
+            Ok(group_role)
+        }
+
+        // Anyone can end an elected admin's term once it's over, which makes them a member again.
+        #[ink(message)]
+        fn group_user_terms_destroy(
+            &mut self,
+            group_id: u32,
+            user: AccountId,
+        ) -> Result<GroupUser, AZGroupsError> {
+            let term_ends_at: Timestamp = self.group_user_terms_show(group_id, user)?;
+            if self.env().block_timestamp() < term_ends_at {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Term has not ended".to_string(),
+                ));
+            }
+
+            let group_user: GroupUser = self.apply_group_users_update(group_id, user, Role::Member);
+
+            // emit event
+            self.env()
+                .emit_event(GroupUserTermDestroy { group_id, user });
+
+            Ok(group_user)
+        }
+
+        // Returns when an elected admin's term ends.
+        #[ink(message)]
+        fn group_user_terms_show(
+            &self,
+            group_id: u32,
+            user: AccountId,
+        ) -> Result<Timestamp, AZGroupsError> {
+            self.group_user_terms
+                .get((group_id, user))
+                .ok_or(AZGroupsError::NotFound("GroupUserTerm".to_string()))
+        }
+
+        // Admin or above can set the title of members with the same role or less.
+        #[ink(message)]
+        fn group_user_titles_update(
+            &mut self,
+            group_id: u32,
+            user: AccountId,
+            title: Option<String>,
+        ) -> Result<GroupUserProfile, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            let caller_group_user: GroupUser = self.group_users_show(group_id, caller)?;
+            let user_group_user: GroupUser = self.validate_member(group_id, user)?;
+            if caller_group_user.role.to_int() < 3
+                || caller_group_user.role.to_int() < user_group_user.role.to_int()
+            {
+                return Err(AZGroupsError::Unauthorised);
+            }
+            self.group_super_admin_activities_record(group_id, caller);
+            let title: Option<String> =
+                AZGroups::group_user_profile_field_format(title, "Title is too long")?;
+
+            let mut profile: GroupUserProfile = self.group_user_profiles_show(group_id, user)?;
+            profile.title = title;
+            self.group_user_profiles.insert((group_id, user), &profile);
+
+            // emit event
+            self.env().emit_event(GroupUserProfileUpdate {
+                group_id,
+                user,
+                profile: profile.clone(),
+            });
+
+            Ok(profile)
+        }
+
+        // Users with the update weights capability can set the weight of another member with the same role or less.
+        // The weight has to be between 1 and the group's weight limit.
+        #[ink(message)]
+        fn group_user_weights_update(
+            &mut self,
+            group_id: u32,
+            user: AccountId,
+            weight: u128,
+        ) -> Result<GroupUser, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            if caller == user {
+                return Err(AZGroupsError::Unauthorised);
+            }
+            let caller_group_user: GroupUser =
+                self.validate_capability(group_id, caller, CAPABILITY_UPDATE_WEIGHTS)?;
+            let mut user_group_user: GroupUser = self.validate_member(group_id, user)?;
+            if caller_group_user.role.to_int() < user_group_user.role.to_int() {
+                return Err(AZGroupsError::Unauthorised);
+            }
+            self.group_super_admin_activities_record(group_id, caller);
+            if weight == 0 || weight > self.group_weight_limits_show(group_id) {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Weight must be between 1 and the group's weight limit".to_string(),
+                ));
+            }
+
+            let previous_group_user: GroupUser = user_group_user.clone();
+            user_group_user.weight = weight;
+            self.group_weight_totals_update(
+                group_id,
+                Some(&previous_group_user),
+                Some(&user_group_user),
+            );
+            self.group_users.insert((group_id, user), &user_group_user);
+
+            // emit event
+            self.env().emit_event(GroupUserWeightUpdate {
+                group_id,
+                user,
+                weight,
+            });
 
             Ok(user_group_user)
         }
 
-        #[ink(message)]
-        pub fn groups_create(&mut self, name: String) -> Result<Group, AZGroupsError> {
-            let formatted_name: String = name.trim().to_string();
-            AZGroups::validate_group_name_presence(formatted_name.clone())?;
-            if self.groups_total == u32::MAX {
-                return Err(AZGroupsError::UnprocessableEntity(
-                    "Group limit reached".to_string(),
-                ));
+        // When the group has a join condition, the condition contract is called with the applicant.
+        // If the condition isn't met, the application is rejected.
+        // If the condition is met and the policy is AutoApprove, the applicant is created as a member.
+        #[ink(message)]
+        fn group_users_create(&mut self, group_id: u32) -> Result<GroupUser, AZGroupsError> {
+            // check if group exists
+            self.groups_show(group_id)?;
+            // check if group user already exists
+            let user: AccountId = Self::env().caller();
+            if self.group_users.get((group_id, user)).is_some() {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Group user has already been taken".to_string(),
+                ));
+            }
+            // check join condition
+            let mut role: Role = Role::Applicant;
+            if let Some(group_join_condition) = self.group_join_conditions.get(group_id) {
+                if !self.join_condition_check(group_join_condition.address, group_id, user)? {
+                    return Err(AZGroupsError::UnprocessableEntity(
+                        "Join condition not met".to_string(),
+                    ));
+                }
+                if group_join_condition.policy == JoinConditionPolicy::AutoApprove {
+                    role = Role::Member
+                }
+            }
+
+            // Create and set group user
+            let group_user: GroupUser = GroupUser { role, weight: 1 };
+            self.group_users.insert((group_id, user), &group_user);
+            self.group_weight_totals_update(group_id, None, Some(&group_user));
+            self.group_role_totals_update(group_id, None, Some(group_user.role.clone()));
+
+            // emit event
+            self.env().emit_event(GroupUserCreate {
+                group_id,
+                user,
+                role: group_user.role.clone(),
+            });
+
+            // notify observers
+            self.group_observers_notify(
+                group_id,
+                ink::selector_bytes!("GroupObserver::on_group_user_create"),
+                (group_id, user, group_user.role.clone()),
+            );
+
+            Ok(group_user)
+        }
+
+        // User can leave the group, as long as they aren't a super admin or banned.
+        // Super admins can't kick themselves because there's a chance that the group would be left without one.
+        // The only way a super admin can leave the group is to be kicked by another super admin.
+        // User can be kicked by an admin or super-admin, as long as they are of the same role level or less.
+        #[ink(message)]
+        fn group_users_destroy(
+            &mut self,
+            group_id: u32,
+            user: AccountId,
+        ) -> Result<(), AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            let caller_group_user: GroupUser = self.group_users_show(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            let user_group_user: GroupUser = self.group_users_show(group_id, user)?;
+            let caller_group_user_role_as_int: u8 = caller_group_user.role.to_int();
+            if caller == user {
+                if caller_group_user_role_as_int == 4 || caller_group_user_role_as_int == 0 {
+                    return Err(AZGroupsError::Unauthorised);
+                }
+            } else {
+                // Removing a banned user lifts the ban, so it needs the ban capability
+                let capability: u32 = if user_group_user.role == Role::Banned {
+                    CAPABILITY_BAN
+                } else {
+                    CAPABILITY_KICK
+                };
+                self.validate_capability(group_id, caller, capability)?;
+                if caller_group_user_role_as_int < user_group_user.role.to_int() {
+                    return Err(AZGroupsError::Unauthorised);
+                }
+            }
+            self.validate_super_admin_removal(group_id, user)?;
+            if user_group_user.role == Role::SuperAdmin && self.group_multisigs_show(group_id) > 1 {
+                self.pending_operations_create(
+                    group_id,
+                    caller,
+                    PendingOperationAction::GroupUserDestroy { user },
+                )?;
+                return Ok(());
+            }
+
+            self.apply_group_users_destroy(group_id, user);
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn group_users_show(
+            &self,
+            group_id: u32,
+            user: AccountId,
+        ) -> Result<GroupUser, AZGroupsError> {
+            let mut group_user: GroupUser = self
+                .group_users
+                .get((group_id, user))
+                .ok_or(AZGroupsError::NotFound("GroupUser".to_string()))?;
+            // An elected admin is a member once their term ends, even before the term is destroyed
+            if group_user.role == Role::Admin
+                && self
+                    .group_user_terms
+                    .get((group_id, user))
+                    .is_some_and(|term_ends_at| self.env().block_timestamp() >= term_ends_at)
+            {
+                group_user.role = Role::Member;
+            }
+
+            Ok(group_user)
+        }
+
+        #[ink(message)]
+        fn group_users_update(
+            &mut self,
+            group_id: u32,
+            user: AccountId,
+            role: Role,
+        ) -> Result<GroupUser, AZGroupsError> {
+            let role_as_int: u8 = role.to_int();
+            let caller: AccountId = Self::env().caller();
+            if caller == user {
+                return Err(AZGroupsError::Unauthorised);
+            }
+            let caller_group_user: GroupUser = self.group_users_show(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            let caller_group_user_as_int: u8 = caller_group_user.role.to_int();
+            let user_group_user: GroupUser = self.group_users_show(group_id, user)?;
+            let capability: u32 = if user_group_user.role == Role::Banned || role == Role::Banned {
+                CAPABILITY_BAN
+            } else if user_group_user.role == Role::Applicant && role == Role::Member {
+                CAPABILITY_APPROVE_APPLICANTS
+            } else {
+                CAPABILITY_MANAGE_ROLES
+            };
+            self.validate_capability(group_id, caller, capability)?;
+            if caller_group_user_as_int < user_group_user.role.to_int() {
+                return Err(AZGroupsError::Unauthorised);
+            }
+            if role_as_int > caller_group_user_as_int {
+                return Err(AZGroupsError::Unauthorised);
+            }
+            // Promotions to admin or super admin are queued when the group has a promotion delay
+            if role_as_int >= 3
+                && role_as_int > user_group_user.role.to_int()
+                && self.group_promotion_delays_show(group_id) > 0
+            {
+                self.pending_promotions_create(group_id, user, role, caller, None)?;
+                return Ok(user_group_user);
+            }
+            if user_group_user.role == Role::SuperAdmin && role != Role::SuperAdmin {
+                self.validate_super_admin_removal(group_id, user)?;
+                if self.group_multisigs_show(group_id) > 1 {
+                    self.pending_operations_create(
+                        group_id,
+                        caller,
+                        PendingOperationAction::GroupUserUpdate { user, role },
+                    )?;
+                    return Ok(user_group_user);
+                }
+            }
+
+            Ok(self.apply_group_users_update(group_id, user, role))
+        }
+
+        // Replaces any existing verification of the group.
+        #[ink(message)]
+        fn group_verifications_create(
+            &mut self,
+            group_id: u32,
+            evidence_hash: Hash,
+            expires_at: Timestamp,
+        ) -> Result<Group, AZGroupsError> {
+            let mut group: Group = self.groups_stored_show(group_id)?;
+            let verifier: AccountId = Self::env().caller();
+            self.verifiers_show(verifier)
+                .map_err(|_| AZGroupsError::Unauthorised)?;
+            if expires_at <= self.env().block_timestamp() {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Expiry must be in the future".to_string(),
+                ));
+            }
+
+            group.verification = Some(GroupVerification {
+                verifier,
+                evidence_hash,
+                expires_at,
+            });
+            self.groups.insert(group_id, &group);
+
+            // emit event
+            self.env().emit_event(GroupVerificationCreate {
+                group_id,
+                verifier,
+                evidence_hash,
+                expires_at,
+            });
+
+            Ok(group)
+        }
+
+        // Any verifier can revoke a verification.
+        #[ink(message)]
+        fn group_verifications_destroy(&mut self, group_id: u32) -> Result<(), AZGroupsError> {
+            let mut group: Group = self.groups_stored_show(group_id)?;
+            let verifier: AccountId = Self::env().caller();
+            self.verifiers_show(verifier)
+                .map_err(|_| AZGroupsError::Unauthorised)?;
+            if group.verification.take().is_none() {
+                return Err(AZGroupsError::NotFound("GroupVerification".to_string()));
+            }
+            self.groups.insert(group_id, &group);
+
+            // emit event
+            self.env()
+                .emit_event(GroupVerificationDestroy { group_id, verifier });
+
+            Ok(())
+        }
+
+        // Returns the number of member vouches needed for an applicant to become a member.
+        // 0 means that vouching isn't enabled for the group.
+        #[ink(message)]
+        fn group_vouch_thresholds_show(&self, group_id: u32) -> u8 {
+            self.group_vouch_thresholds
+                .get(group_id)
+                .unwrap_or_default()
+        }
+
+        #[ink(message)]
+        fn group_vouch_thresholds_update(
+            &mut self,
+            group_id: u32,
+            threshold: u8,
+        ) -> Result<u8, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+
+            self.group_vouch_thresholds.insert(group_id, &threshold);
+
+            // emit event
+            self.env().emit_event(GroupVouchThresholdUpdate {
+                group_id,
+                threshold,
+            });
+
+            Ok(threshold)
+        }
+
+        #[ink(message)]
+        fn group_weight_limits_show(&self, group_id: u32) -> u128 {
+            self.group_weight_limits.get(group_id).unwrap_or(1)
+        }
+
+        // Lowering the limit doesn't change the weight of existing members.
+        #[ink(message)]
+        fn group_weight_limits_update(
+            &mut self,
+            group_id: u32,
+            limit: u128,
+        ) -> Result<u128, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            if limit == 0 {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Limit must be greater than zero".to_string(),
+                ));
+            }
+
+            self.group_weight_limits.insert(group_id, &limit);
+
+            // emit event
+            self.env()
+                .emit_event(GroupWeightLimitUpdate { group_id, limit });
+
+            Ok(limit)
+        }
+
+        #[ink(message)]
+        fn group_weight_totals_show(&self, group_id: u32) -> u128 {
+            self.group_weight_totals.get(group_id).unwrap_or_default()
+        }
+
+        #[ink(message)]
+        fn groups_create(&mut self, name: String) -> Result<Group, AZGroupsError> {
+            let formatted_name: String = names::format_group_name(name);
+            AZGroups::validate_group_name_presence(formatted_name.clone())?;
+            self.validate_group_name_rules(&formatted_name)?;
+            if self.groups_total == u32::MAX {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Group limit reached".to_string(),
+                ));
+            }
+            // check if group with key already exists
+            let key: String = names::group_name_key(&formatted_name);
+            self.validate_group_name_uniqueness(key.clone(), None)?;
+            let slug: String = self.group_slug_derive(&formatted_name, self.groups_total);
+
+            let user: AccountId = Self::env().caller();
+            // Create group
+            let group: Group = Group {
+                id: self.groups_total,
+                name: formatted_name.clone(),
+                enabled: true,
+                domain: None,
+                verification: None,
+            };
+            self.groups.insert(group.id, &group);
+
+            // Map group name to id
+            self.group_name_redirects.remove(key.clone());
+            self.group_id_by_name.insert(key, &group.id);
+
+            // Create and set group user
+            let group_user: GroupUser = GroupUser {
+                role: Role::SuperAdmin,
+                weight: 1,
+            };
+            self.group_users.insert((group.id, user), &group_user);
+            self.group_weight_totals_update(group.id, None, Some(&group_user));
+            self.group_role_totals_update(group.id, None, Some(group_user.role.clone()));
+            self.group_super_admin_activities_record(group.id, user);
+
+            // Increase groups_total
+            self.groups_total += 1;
+
+            // emit event
+            self.env().emit_event(Create {
+                id: group.id,
+                name: formatted_name,
+            });
+            self.env().emit_event(GroupUserCreate {
+                group_id: group.id,
+                user,
+                role: group_user.role,
+            });
+            self.apply_group_slugs_update(group.id, slug);
+
+            Ok(group)
+        }
+
+        // Previous names resolve to the group until their cooldown ends.
+        #[ink(message)]
+        fn groups_find_by_name(&self, name: String) -> Result<GroupNameLookup, AZGroupsError> {
+            let key: String = names::group_name_key(&name);
+            if let Some(group_id) = self.group_id_by_name.get(key.clone()) {
+                return Ok(GroupNameLookup {
+                    group: self.groups_show(group_id)?,
+                    redirected: false,
+                });
+            }
+            match self.group_name_redirects.get(key) {
+                Some(redirect) if redirect.expires_at > self.env().block_timestamp() => {
+                    Ok(GroupNameLookup {
+                        group: self.groups_show(redirect.group_id)?,
+                        redirected: true,
+                    })
+                }
+                _ => Err(AZGroupsError::NotFound("Group".to_string())),
+            }
+        }
+
+        #[ink(message)]
+        fn groups_find_by_slug(&self, slug: String) -> Result<Group, AZGroupsError> {
+            if let Some(group_id) = self.group_id_by_slug.get(slug) {
+                self.groups_show(group_id)
+            } else {
+                Err(AZGroupsError::NotFound("Group".to_string()))
+            }
+        }
+
+        #[ink(message)]
+        fn groups_show(&self, id: u32) -> Result<Group, AZGroupsError> {
+            let mut group: Group = self.groups_stored_show(id)?;
+            group.verification = group.verification.filter(|verification| {
+                verification.expires_at > self.env().block_timestamp()
+                    && self.verifiers.contains(verification.verifier)
+            });
+
+            Ok(group)
+        }
+
+        #[ink(message)]
+        fn groups_update(
+            &mut self,
+            id: u32,
+            name: String,
+            enabled: bool,
+        ) -> Result<Group, AZGroupsError> {
+            let group: Group = self.groups_show(id)?;
+            let caller: AccountId = Self::env().caller();
+            self.validate_capability(id, caller, CAPABILITY_UPDATE_GROUP)?;
+            self.group_super_admin_activities_record(id, caller);
+            // Renaming and disabling have to be confirmed when the group has a multisig
+            let renaming: bool = names::format_group_name(name.clone()) != group.name;
+            let disabling: bool = group.enabled && !enabled;
+            if (renaming || disabling) && self.group_multisigs_show(id) > 1 {
+                self.pending_operations_create(
+                    id,
+                    caller,
+                    PendingOperationAction::GroupsUpdate { name, enabled },
+                )?;
+                return Ok(group);
+            }
+
+            self.apply_groups_update(id, name, enabled)
+        }
+
+        #[ink(message)]
+        fn owner_show(&self) -> AccountId {
+            self.owner
+        }
+
+        // Confirmations are only counted for users that are still super admins.
+        // The operation is executed as soon as enough confirmations have been made.
+        #[ink(message)]
+        fn pending_operations_confirm(
+            &mut self,
+            id: u32,
+        ) -> Result<PendingOperation, AZGroupsError> {
+            let mut pending_operation: PendingOperation = self
+                .pending_operations
+                .get(id)
+                .ok_or(AZGroupsError::NotFound("PendingOperation".to_string()))?;
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(pending_operation.group_id, caller)?;
+            self.group_super_admin_activities_record(pending_operation.group_id, caller);
+            if pending_operation.confirmations.contains(&caller) {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Confirmation has already been taken".to_string(),
+                ));
+            }
+
+            pending_operation.confirmations.push(caller);
+            self.pending_operations.insert(id, &pending_operation);
+
+            // emit event
+            self.env().emit_event(PendingOperationConfirm {
+                id,
+                confirmer: caller,
+            });
+
+            // execute if confirmed
+            let group_id: u32 = pending_operation.group_id;
+            let confirmations_total: usize = pending_operation
+                .confirmations
+                .iter()
+                .filter(|confirmer| self.validate_super_admin(group_id, **confirmer).is_ok())
+                .count();
+            if confirmations_total >= usize::from(self.group_multisigs_show(group_id)) {
+                self.pending_operations_execute(&pending_operation)?;
+            }
+
+            Ok(pending_operation)
+        }
+
+        #[ink(message)]
+        fn pending_operations_index(&self, group_id: u32) -> Vec<PendingOperation> {
+            self.group_pending_operations
+                .get(group_id)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|id| self.pending_operations.get(id))
+                .collect()
+        }
+
+        // The operation is removed once it has no confirmations left.
+        #[ink(message)]
+        fn pending_operations_revoke(
+            &mut self,
+            id: u32,
+        ) -> Result<PendingOperation, AZGroupsError> {
+            let mut pending_operation: PendingOperation = self
+                .pending_operations
+                .get(id)
+                .ok_or(AZGroupsError::NotFound("PendingOperation".to_string()))?;
+            let caller: AccountId = Self::env().caller();
+            let Some(index) = pending_operation
+                .confirmations
+                .iter()
+                .position(|confirmer| *confirmer == caller)
+            else {
+                return Err(AZGroupsError::NotFound("Confirmation".to_string()));
+            };
+
+            pending_operation.confirmations.remove(index);
+            if pending_operation.confirmations.is_empty() {
+                self.pending_operations_remove(&pending_operation);
+            } else {
+                self.pending_operations.insert(id, &pending_operation);
+            }
+
+            // emit event
+            self.env().emit_event(PendingOperationRevoke {
+                id,
+                confirmer: caller,
+            });
+
+            Ok(pending_operation)
+        }
+
+        // Super admins can veto a promotion while it is pending.
+        #[ink(message)]
+        fn pending_promotions_destroy(
+            &mut self,
+            group_id: u32,
+            user: AccountId,
+        ) -> Result<(), AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_super_admin(group_id, caller)?;
+            self.group_super_admin_activities_record(group_id, caller);
+            if self.pending_promotions.get((group_id, user)).is_none() {
+                return Err(AZGroupsError::NotFound("PendingPromotion".to_string()));
+            }
+
+            self.pending_promotions_remove(group_id, user);
+
+            // emit event
+            self.env().emit_event(PendingPromotionDestroy {
+                group_id,
+                user,
+                vetoer: caller,
+            });
+
+            Ok(())
+        }
+
+        // Anyone can execute a promotion once its delay has passed,
+        // as long as the promoter still has a role high enough to make it.
+        // Elected admins don't depend on the promoter, and their term starts from the execution.
+        #[ink(message)]
+        fn pending_promotions_execute(
+            &mut self,
+            group_id: u32,
+            user: AccountId,
+        ) -> Result<GroupUser, AZGroupsError> {
+            let pending_promotion: PendingPromotion = self
+                .pending_promotions
+                .get((group_id, user))
+                .ok_or(AZGroupsError::NotFound("PendingPromotion".to_string()))?;
+            if self.env().block_timestamp() < pending_promotion.executable_at {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Promotion delay has not passed".to_string(),
+                ));
+            }
+            let promoter_role: Role = self
+                .group_users_show(group_id, pending_promotion.promoter)
+                .map(|group_user| group_user.role)
+                .unwrap_or(Role::Banned);
+            if pending_promotion.election_id.is_none()
+                && promoter_role.to_int() < pending_promotion.role.to_int()
+            {
+                return Err(AZGroupsError::Unauthorised);
+            }
+
+            let group_user: GroupUser =
+                self.apply_group_users_update(group_id, user, pending_promotion.role.clone());
+            if let Some(election) = pending_promotion
+                .election_id
+                .and_then(|election_id| self.elections.get(election_id))
+            {
+                let term_ends_at: Timestamp =
+                    self.env().block_timestamp().saturating_add(election.term);
+                self.group_user_terms_insert(group_id, user, term_ends_at);
+            }
+
+            // emit event
+            self.env().emit_event(PendingPromotionExecute {
+                group_id,
+                user,
+                role: pending_promotion.role,
+            });
+
+            Ok(group_user)
+        }
+
+        #[ink(message)]
+        fn pending_promotions_index(&self, group_id: u32) -> Vec<PendingPromotion> {
+            self.group_pending_promotions
+                .get(group_id)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|user| self.pending_promotions.get((group_id, user)))
+                .collect()
+        }
+
+        // Members can sign an active petition once.
+        // The action is carried out as soon as the share of members that have signed reaches the threshold.
+        #[ink(message)]
+        fn petition_signatures_create(
+            &mut self,
+            petition_id: u32,
+        ) -> Result<Petition, AZGroupsError> {
+            let mut petition: Petition = self.petitions_show(petition_id)?;
+            let caller: AccountId = Self::env().caller();
+            self.validate_member(petition.group_id, caller)?;
+            if caller == petition.target {
+                return Err(AZGroupsError::Unauthorised);
+            }
+            if petition.status != PetitionStatus::Active {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Petition is not active".to_string(),
+                ));
+            }
+            if self.env().block_timestamp() >= petition.ends_at {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Petition has ended".to_string(),
+                ));
+            }
+            if self
+                .petition_signatures
+                .get((petition_id, caller))
+                .is_some()
+            {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Signature has already been taken".to_string(),
+                ));
+            }
+
+            self.petition_signatures.insert((petition_id, caller), &());
+            petition.signatures += 1;
+
+            // emit event
+            self.env().emit_event(PetitionSignatureCreate {
+                petition_id,
+                signer: caller,
+            });
+
+            // execute if threshold reached
+            let members_total: u32 = self.group_members_total(petition.group_id);
+            if u64::from(petition.signatures) * 100
+                >= u64::from(petition.threshold) * u64::from(members_total)
+            {
+                petition.status = if self.petitions_execute(&petition).is_ok() {
+                    PetitionStatus::Executed
+                } else {
+                    PetitionStatus::Failed
+                };
+
+                // emit event
+                self.env().emit_event(PetitionExecute {
+                    id: petition_id,
+                    group_id: petition.group_id,
+                    target: petition.target,
+                    status: petition.status.clone(),
+                });
+            }
+            self.petitions.insert(petition_id, &petition);
+
+            Ok(petition)
+        }
+
+        // Members can petition to demote or remove an admin.
+        // Super admins can only be petitioned against when the group has a super admin threshold.
+        // The creator signs the petition straight away.
+        #[ink(message)]
+        fn petitions_create(
+            &mut self,
+            group_id: u32,
+            target: AccountId,
+            action: PetitionAction,
+        ) -> Result<Petition, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_member(group_id, caller)?;
+            if caller == target {
+                return Err(AZGroupsError::Unauthorised);
+            }
+            let group_impeachment: GroupImpeachment = self.group_impeachments_show(group_id)?;
+            let threshold: u8 = match self.group_users_show(group_id, target)?.role {
+                Role::Admin => group_impeachment.admin_threshold,
+                Role::SuperAdmin if group_impeachment.super_admin_threshold > 0 => {
+                    group_impeachment.super_admin_threshold
+                }
+                _ => return Err(AZGroupsError::Unauthorised),
+            };
+            if self.petitions_total == u32::MAX {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Petition limit reached".to_string(),
+                ));
+            }
+
+            let petition: Petition = Petition {
+                id: self.petitions_total,
+                group_id,
+                target,
+                action: action.clone(),
+                threshold,
+                ends_at: self
+                    .env()
+                    .block_timestamp()
+                    .saturating_add(group_impeachment.window),
+                signatures: 0,
+                status: PetitionStatus::Active,
+            };
+            self.petitions.insert(petition.id, &petition);
+            self.petitions_total += 1;
+
+            // emit event
+            self.env().emit_event(PetitionCreate {
+                id: petition.id,
+                group_id,
+                target,
+                action,
+            });
+
+            self.petition_signatures_create(petition.id)
+        }
+
+        #[ink(message)]
+        fn petitions_show(&self, id: u32) -> Result<Petition, AZGroupsError> {
+            self.petitions
+                .get(id)
+                .ok_or(AZGroupsError::NotFound("Petition".to_string()))
+        }
+
+        // Members can vote for one option while the poll is open.
+        // Each member's vote counts once, whatever their weight. Tallies are final once the poll closes.
+        #[ink(message)]
+        fn poll_votes_create(&mut self, poll_id: u32, option: u8) -> Result<Poll, AZGroupsError> {
+            let mut poll: Poll = self.polls_show(poll_id)?;
+            let caller: AccountId = Self::env().caller();
+            self.validate_membership(poll.group_id, caller)?;
+            let block_timestamp: Timestamp = self.env().block_timestamp();
+            if block_timestamp < poll.opens_at {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Poll hasn't opened".to_string(),
+                ));
+            }
+            if block_timestamp >= poll.closes_at {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Poll has closed".to_string(),
+                ));
+            }
+            if usize::from(option) >= poll.tallies.len() {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Option is invalid".to_string(),
+                ));
+            }
+            if self.poll_votes.get((poll_id, caller)).is_some() {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Vote has already been taken".to_string(),
+                ));
+            }
+
+            self.poll_votes.insert((poll_id, caller), &option);
+            let tally: &mut u32 = &mut poll.tallies[usize::from(option)];
+            *tally = tally.saturating_add(1);
+            self.polls.insert(poll_id, &poll);
+
+            // emit event
+            self.env().emit_event(PollVoteCreate {
+                poll_id,
+                voter: caller,
+                option,
+            });
+
+            Ok(poll)
+        }
+
+        // Admins and super admins can create non-binding polls with up to 10 options.
+        #[ink(message)]
+        fn polls_create(
+            &mut self,
+            group_id: u32,
+            question_hash: Hash,
+            options: u8,
+            opens_at: Timestamp,
+            closes_at: Timestamp,
+        ) -> Result<Poll, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            if self.group_users_show(group_id, caller)?.role.to_int() < 3 {
+                return Err(AZGroupsError::Unauthorised);
+            }
+            self.group_super_admin_activities_record(group_id, caller);
+            if !(2..=POLL_OPTIONS_LIMIT).contains(&options) {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Options must be between 2 and 10".to_string(),
+                ));
+            }
+            if closes_at <= opens_at || closes_at <= self.env().block_timestamp() {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Closes at must be after opens at and in the future".to_string(),
+                ));
+            }
+            if self.polls_total == u32::MAX {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Poll limit reached".to_string(),
+                ));
+            }
+
+            let poll: Poll = Poll {
+                id: self.polls_total,
+                group_id,
+                creator: caller,
+                question_hash,
+                opens_at,
+                closes_at,
+                tallies: (0..options).map(|_| 0).collect(),
+            };
+            self.polls.insert(poll.id, &poll);
+            self.polls_total += 1;
+
+            // emit event
+            self.env().emit_event(PollCreate {
+                id: poll.id,
+                group_id,
+                question_hash,
+                options,
+                opens_at,
+                closes_at,
+            });
+
+            Ok(poll)
+        }
+
+        #[ink(message)]
+        fn polls_show(&self, id: u32) -> Result<Poll, AZGroupsError> {
+            self.polls
+                .get(id)
+                .ok_or(AZGroupsError::NotFound("Poll".to_string()))
+        }
+
+        // Members can vote once on an active proposal.
+        // The proposal is executed as soon as the quorum and threshold are reached.
+        #[ink(message)]
+        fn proposal_votes_create(
+            &mut self,
+            proposal_id: u32,
+            support: bool,
+        ) -> Result<Proposal, AZGroupsError> {
+            let mut proposal: Proposal = self.proposals_show(proposal_id)?;
+            let caller: AccountId = Self::env().caller();
+            let caller_group_user: GroupUser = self.validate_member(proposal.group_id, caller)?;
+            if proposal.status != ProposalStatus::Active {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Proposal is not active".to_string(),
+                ));
+            }
+            if self.env().block_timestamp() >= proposal.ends_at {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Voting period has ended".to_string(),
+                ));
+            }
+            if self.proposal_votes.get((proposal_id, caller)).is_some() {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Vote has already been taken".to_string(),
+                ));
+            }
+
+            self.proposal_votes.insert((proposal_id, caller), &support);
+            if support {
+                proposal.votes_for = proposal.votes_for.saturating_add(caller_group_user.weight)
+            } else {
+                proposal.votes_against = proposal
+                    .votes_against
+                    .saturating_add(caller_group_user.weight)
+            }
+
+            // emit event
+            self.env().emit_event(ProposalVoteCreate {
+                proposal_id,
+                voter: caller,
+                support,
+            });
+
+            // execute if passed
+            let votes_total: u128 = proposal.votes_for.saturating_add(proposal.votes_against);
+            if votes_total >= u128::from(proposal.quorum)
+                && proposal.votes_for.saturating_mul(100)
+                    >= u128::from(proposal.threshold).saturating_mul(votes_total)
+            {
+                proposal.status = if self.proposals_execute(&proposal).is_ok() {
+                    ProposalStatus::Executed
+                } else {
+                    ProposalStatus::Failed
+                };
+
+                // emit event
+                self.env().emit_event(ProposalExecute {
+                    id: proposal_id,
+                    group_id: proposal.group_id,
+                    status: proposal.status.clone(),
+                });
+            }
+            self.proposals.insert(proposal_id, &proposal);
+
+            Ok(proposal)
+        }
+
+        #[ink(message)]
+        fn proposals_create(
+            &mut self,
+            group_id: u32,
+            description_hash: Hash,
+            action: ProposalAction,
+        ) -> Result<Proposal, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_member(group_id, caller)?;
+            let group_governance: GroupGovernance = self.group_governances_show(group_id)?;
+            self.validate_proposal_action(group_id, &action)?;
+            if self.proposals_total == u32::MAX {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Proposal limit reached".to_string(),
+                ));
+            }
+
+            let proposal: Proposal = Proposal {
+                id: self.proposals_total,
+                group_id,
+                proposer: caller,
+                description_hash,
+                action: action.clone(),
+                ends_at: self
+                    .env()
+                    .block_timestamp()
+                    .saturating_add(group_governance.voting_period),
+                quorum: group_governance.quorum,
+                threshold: group_governance.threshold,
+                votes_for: 0,
+                votes_against: 0,
+                status: ProposalStatus::Active,
+            };
+            self.proposals.insert(proposal.id, &proposal);
+            self.proposals_total += 1;
+
+            // emit event
+            self.env().emit_event(ProposalCreate {
+                id: proposal.id,
+                group_id,
+                proposer: caller,
+                action,
+            });
+
+            Ok(proposal)
+        }
+
+        #[ink(message)]
+        fn proposals_show(&self, id: u32) -> Result<Proposal, AZGroupsError> {
+            self.proposals
+                .get(id)
+                .ok_or(AZGroupsError::NotFound("Proposal".to_string()))
+        }
+
+        // Reserved names are matched on their key, so lookalikes of a reserved name are reserved too.
+        // Groups that already have the name keep it.
+        #[ink(message)]
+        fn reserved_names_create(&mut self, name: String) -> Result<String, AZGroupsError> {
+            if Self::env().caller() != self.owner {
+                return Err(AZGroupsError::Unauthorised);
+            }
+            let name_formatted: String = names::format_group_name(name);
+            AZGroups::validate_group_name_presence(name_formatted.clone())?;
+            let key: String = names::group_name_key(&name_formatted);
+            if self.reserved_names.get(key.clone()).is_some() {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Reserved name has already been taken".to_string(),
+                ));
+            }
+
+            self.reserved_names.insert(key, &name_formatted);
+
+            // emit event
+            self.env().emit_event(ReservedNameCreate {
+                name: name_formatted.clone(),
+            });
+
+            Ok(name_formatted)
+        }
+
+        #[ink(message)]
+        fn reserved_names_destroy(&mut self, name: String) -> Result<(), AZGroupsError> {
+            if Self::env().caller() != self.owner {
+                return Err(AZGroupsError::Unauthorised);
+            }
+            let name_formatted: String = self.reserved_names_show(name)?;
+
+            self.reserved_names
+                .remove(names::group_name_key(&name_formatted));
+
+            // emit event
+            self.env().emit_event(ReservedNameDestroy {
+                name: name_formatted,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn reserved_names_show(&self, name: String) -> Result<String, AZGroupsError> {
+            self.reserved_names
+                .get(names::group_name_key(&name))
+                .ok_or(AZGroupsError::NotFound("ReservedName".to_string()))
+        }
+
+        #[ink(message)]
+        fn super_admin_claim_supports_create(
+            &mut self,
+            group_id: u32,
+        ) -> Result<SuperAdminClaim, AZGroupsError> {
+            let mut super_admin_claim: SuperAdminClaim = self.super_admin_claims_show(group_id)?;
+            let caller: AccountId = Self::env().caller();
+            self.validate_admin(group_id, caller)?;
+            if super_admin_claim.supporters.contains(&caller) {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Support has already been taken".to_string(),
+                ));
+            }
+
+            super_admin_claim.supporters.push(caller);
+            self.super_admin_claims.insert(group_id, &super_admin_claim);
+
+            // emit event
+            self.env().emit_event(SuperAdminClaimSupportCreate {
+                group_id,
+                supporter: caller,
+            });
+
+            Ok(super_admin_claim)
+        }
+
+        // When no super admin has been active for the group's inactivity period,
+        // an admin can claim super admin. The claim needs the support of a majority of admins
+        // and can be cancelled by a super admin until the challenge period ends.
+        // A claim that has passed its challenge period, or whose claimant is no longer an admin, is replaced.
+        #[ink(message)]
+        fn super_admin_claims_create(
+            &mut self,
+            group_id: u32,
+        ) -> Result<SuperAdminClaim, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            self.validate_admin(group_id, caller)?;
+            let group_recovery: GroupRecovery = self.group_recoveries_show(group_id)?;
+            let block_timestamp: Timestamp = self.env().block_timestamp();
+            if block_timestamp
+                < self
+                    .group_super_admin_activities_show(group_id)
+                    .saturating_add(group_recovery.inactivity_period)
+            {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Super admin is still active".to_string(),
+                ));
+            }
+            if let Some(super_admin_claim) = self.super_admin_claims.get(group_id) {
+                if !self.super_admin_claim_is_stale(&super_admin_claim) {
+                    return Err(AZGroupsError::UnprocessableEntity(
+                        "Super admin claim has already been taken".to_string(),
+                    ));
+                }
+
+                // emit event
+                self.env().emit_event(SuperAdminClaimDestroy {
+                    group_id,
+                    claimant: super_admin_claim.claimant,
+                    canceller: caller,
+                });
+            }
+
+            let super_admin_claim: SuperAdminClaim = SuperAdminClaim {
+                group_id,
+                claimant: caller,
+                supporters: Vec::from([caller]),
+                challenge_ends_at: block_timestamp.saturating_add(group_recovery.challenge_period),
+            };
+            self.super_admin_claims.insert(group_id, &super_admin_claim);
+
+            // emit event
+            self.env().emit_event(SuperAdminClaimCreate {
+                group_id,
+                claimant: caller,
+                challenge_ends_at: super_admin_claim.challenge_ends_at,
+            });
+
+            Ok(super_admin_claim)
+        }
+
+        // A super admin can cancel a claim at any time before it is executed.
+        // Any admin can clear a claim that has passed its challenge period or whose claimant is no longer an admin.
+        #[ink(message)]
+        fn super_admin_claims_destroy(&mut self, group_id: u32) -> Result<(), AZGroupsError> {
+            let super_admin_claim: SuperAdminClaim = self.super_admin_claims_show(group_id)?;
+            let caller: AccountId = Self::env().caller();
+            if self.validate_super_admin(group_id, caller).is_ok() {
+                self.group_super_admin_activities_record(group_id, caller);
+            } else {
+                self.validate_admin(group_id, caller)?;
+                if !self.super_admin_claim_is_stale(&super_admin_claim) {
+                    return Err(AZGroupsError::Unauthorised);
+                }
+            }
+
+            self.super_admin_claims.remove(group_id);
+
+            // emit event
+            self.env().emit_event(SuperAdminClaimDestroy {
+                group_id,
+                claimant: super_admin_claim.claimant,
+                canceller: caller,
+            });
+
+            Ok(())
+        }
+
+        // Anyone can execute a claim after the challenge period,
+        // as long as a majority of the current admins support it.
+        #[ink(message)]
+        fn super_admin_claims_execute(
+            &mut self,
+            group_id: u32,
+        ) -> Result<GroupUser, AZGroupsError> {
+            let super_admin_claim: SuperAdminClaim = self.super_admin_claims_show(group_id)?;
+            if self.env().block_timestamp() < super_admin_claim.challenge_ends_at {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Challenge period has not ended".to_string(),
+                ));
+            }
+            self.validate_admin(group_id, super_admin_claim.claimant)?;
+            let supporters_total: usize = super_admin_claim
+                .supporters
+                .iter()
+                .filter(|supporter| self.validate_admin(group_id, **supporter).is_ok())
+                .count();
+            let admins_total: u32 = self.group_role_totals_show(group_id, Role::Admin);
+            if supporters_total as u64 * 2 <= u64::from(admins_total) {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Claim needs the support of a majority of admins".to_string(),
+                ));
+            }
+
+            self.super_admin_claims.remove(group_id);
+            let group_user: GroupUser = self.apply_group_users_update(
+                group_id,
+                super_admin_claim.claimant,
+                Role::SuperAdmin,
+            );
+            self.group_super_admin_activities_record(group_id, super_admin_claim.claimant);
+
+            // emit event
+            self.env().emit_event(SuperAdminClaimExecute {
+                group_id,
+                claimant: super_admin_claim.claimant,
+            });
+
+            Ok(group_user)
+        }
+
+        #[ink(message)]
+        fn super_admin_claims_show(&self, group_id: u32) -> Result<SuperAdminClaim, AZGroupsError> {
+            self.super_admin_claims
+                .get(group_id)
+                .ok_or(AZGroupsError::NotFound("SuperAdminClaim".to_string()))
+        }
+
+        // Returns the ids of enabled groups with the tag, from start. limit is capped at 50.
+        // The order changes when groups are removed, so pages can skip or repeat a group.
+        #[ink(message)]
+        fn tag_groups_index(&self, tag: String, start: u32, limit: u32) -> Vec<u32> {
+            let tag: String = AZGroups::format_tag(tag);
+            let total: u32 = self.tag_group_totals.get(tag.clone()).unwrap_or_default();
+            let end: u32 = start
+                .saturating_add(limit.min(TAG_GROUPS_PAGE_LIMIT))
+                .min(total);
+            (start..end)
+                .filter_map(|position| self.tag_groups.get((tag.clone(), position)))
+                .collect()
+        }
+
+        // Groups that already have the tag keep it.
+        #[ink(message)]
+        fn tags_create(&mut self, tag: String) -> Result<String, AZGroupsError> {
+            if Self::env().caller() != self.owner {
+                return Err(AZGroupsError::Unauthorised);
+            }
+            let tag: String = AZGroups::format_tag(tag);
+            if tag.len() > TAG_LENGTH_LIMIT {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Tag is too long".to_string(),
+                ));
+            }
+            if !AZGroups::is_slug(&tag) {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Tag is invalid".to_string(),
+                ));
+            }
+            if self.tags.contains(tag.clone()) {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Tag has already been taken".to_string(),
+                ));
+            }
+
+            self.tags.insert(tag.clone(), &());
+
+            // emit event
+            self.env().emit_event(TagCreate { tag: tag.clone() });
+
+            Ok(tag)
+        }
+
+        #[ink(message)]
+        fn tags_destroy(&mut self, tag: String) -> Result<(), AZGroupsError> {
+            if Self::env().caller() != self.owner {
+                return Err(AZGroupsError::Unauthorised);
+            }
+            let tag: String = self.tags_show(tag)?;
+
+            self.tags.remove(tag.clone());
+
+            // emit event
+            self.env().emit_event(TagDestroy { tag });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn tags_show(&self, tag: String) -> Result<String, AZGroupsError> {
+            let tag: String = AZGroups::format_tag(tag);
+            if !self.tags.contains(tag.clone()) {
+                return Err(AZGroupsError::NotFound("Tag".to_string()));
+            }
+
+            Ok(tag)
+        }
+
+        // Convenience method so that other contract can get this info without having to call two functions
+        // 1. Check that the group is enabled
+        // 2. Check that user has a role with the group greater than or equal to two
+        // Returns the built in role, which is the base of any custom role the user has.
+        #[ink(message)]
+        fn validate_membership(
+            &self,
+            group_id: u32,
+            user: AccountId,
+        ) -> Result<Role, AZGroupsError> {
+            let group: Group = self.groups_show(group_id)?;
+            if !group.enabled {
+                return Err(AZGroupsError::GroupDisabled);
+            }
+            let group_user: GroupUser = self.group_users_show(group_id, user)?;
+            if group_user.role.to_int() < 2 {
+                return Err(AZGroupsError::NotAMember);
+            }
+
+            Ok(group_user.role)
+        }
+
+        // Same as validate_membership, but the group also has to be verified.
+        #[ink(message)]
+        fn validate_verified_membership(
+            &self,
+            group_id: u32,
+            user: AccountId,
+        ) -> Result<Role, AZGroupsError> {
+            if self.groups_show(group_id)?.verification.is_none() {
+                return Err(AZGroupsError::GroupUnverified);
+            }
+
+            self.validate_membership(group_id, user)
+        }
+
+        // Verifiers can verify and unverify groups.
+        // Verifications from a removed verifier are no longer valid.
+        #[ink(message)]
+        fn verifiers_create(&mut self, verifier: AccountId) -> Result<AccountId, AZGroupsError> {
+            if Self::env().caller() != self.owner {
+                return Err(AZGroupsError::Unauthorised);
+            }
+            if self.verifiers.contains(verifier) {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Verifier has already been taken".to_string(),
+                ));
+            }
+
+            self.verifiers.insert(verifier, &());
+
+            // emit event
+            self.env().emit_event(VerifierCreate { verifier });
+
+            Ok(verifier)
+        }
+
+        #[ink(message)]
+        fn verifiers_destroy(&mut self, verifier: AccountId) -> Result<(), AZGroupsError> {
+            if Self::env().caller() != self.owner {
+                return Err(AZGroupsError::Unauthorised);
+            }
+            self.verifiers_show(verifier)?;
+
+            self.verifiers.remove(verifier);
+
+            // emit event
+            self.env().emit_event(VerifierDestroy { verifier });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn verifiers_show(&self, verifier: AccountId) -> Result<AccountId, AZGroupsError> {
+            if !self.verifiers.contains(verifier) {
+                return Err(AZGroupsError::NotFound("Verifier".to_string()));
+            }
+
+            Ok(verifier)
+        }
+
+        // Members can vouch for an applicant.
+        // The applicant becomes a member as soon as enough current members have vouched for them.
+        #[ink(message)]
+        fn vouch(
+            &mut self,
+            group_id: u32,
+            applicant: AccountId,
+        ) -> Result<Vec<AccountId>, AZGroupsError> {
+            let threshold: u8 = self.group_vouch_thresholds_show(group_id);
+            if threshold == 0 {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Vouching is not enabled".to_string(),
+                ));
+            }
+            let caller: AccountId = Self::env().caller();
+            self.validate_member(group_id, caller)?;
+            if self.group_users_show(group_id, applicant)?.role != Role::Applicant {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "User is not an applicant".to_string(),
+                ));
+            }
+            let mut vouches: Vec<AccountId> = self.vouches_index(group_id, applicant);
+            if vouches.contains(&caller) {
+                return Err(AZGroupsError::UnprocessableEntity(
+                    "Vouch has already been taken".to_string(),
+                ));
+            }
+
+            vouches.push(caller);
+            self.vouches.insert((group_id, applicant), &vouches);
+
+            // emit event
+            self.env().emit_event(VouchCreate {
+                group_id,
+                applicant,
+                voucher: caller,
+            });
+
+            // promote if enough members have vouched
+            let vouches_total: usize = vouches
+                .iter()
+                .filter(|voucher| self.validate_member(group_id, **voucher).is_ok())
+                .count();
+            if vouches_total >= usize::from(threshold) {
+                self.apply_group_users_update(group_id, applicant, Role::Member);
+            }
+
+            Ok(vouches)
+        }
+
+        // Vouches can be withdrawn until the applicant becomes a member.
+        #[ink(message)]
+        fn vouches_destroy(
+            &mut self,
+            group_id: u32,
+            applicant: AccountId,
+        ) -> Result<Vec<AccountId>, AZGroupsError> {
+            let caller: AccountId = Self::env().caller();
+            let mut vouches: Vec<AccountId> = self.vouches_index(group_id, applicant);
+            let Some(index) = vouches.iter().position(|voucher| *voucher == caller) else {
+                return Err(AZGroupsError::NotFound("Vouch".to_string()));
+            };
+
+            vouches.remove(index);
+            self.vouches.insert((group_id, applicant), &vouches);
+
+            // emit event
+            self.env().emit_event(VouchDestroy {
+                group_id,
+                applicant,
+                voucher: caller,
+            });
+
+            Ok(vouches)
+        }
+
+        // Returns who has vouched for the applicant.
+        #[ink(message)]
+        fn vouches_index(&self, group_id: u32, applicant: AccountId) -> Vec<AccountId> {
+            self.vouches.get((group_id, applicant)).unwrap_or_default()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::{test::DefaultAccounts, DefaultEnvironment};
+
+        // === CONSTANTS ===
+        const MOCK_GROUP_NAME: &str = "The Next Wave";
+
+        // === HELPERS ===
+        fn init() -> (DefaultAccounts<DefaultEnvironment>, AZGroups) {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            let az_groups = AZGroups::new();
+            (accounts, az_groups)
+        }
+
+        // === TEST HANDLES ===
+        #[ink::test]
+        fn test_domain_registries_update() {
+            let (accounts, mut az_groups) = init();
+            // when caller is not the owner
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // * it raises an error
+            let mut result = az_groups.domain_registries_update(Some(accounts.django));
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is the owner
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // * it sets the registry
+            result = az_groups.domain_registries_update(Some(accounts.django));
+            assert_eq!(result.unwrap(), Some(accounts.django));
+            assert_eq!(az_groups.domain_registries_show(), Some(accounts.django));
+            // * it unsets the registry
+            result = az_groups.domain_registries_update(None);
+            assert_eq!(result.unwrap(), None);
+            assert_eq!(az_groups.domain_registries_show(), None);
+        }
+
+        #[ink::test]
+        fn test_election_nominations_create() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            for user in [accounts.charlie, accounts.django, accounts.eve] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(user);
+                az_groups.group_users_create(0).unwrap();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Member)
+                .unwrap();
+            // when election does not exist
+            // * it raises an error
+            let mut result = az_groups.election_nominations_create(0, accounts.charlie);
+            assert_eq!(result, Err(AZGroupsError::NotFound("Election".to_string())));
+            // when election exists
+            az_groups.elections_create(0, 1, 100, 100, 1_000).unwrap();
+            // = when caller is not a member
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // = * it raises an error
+            result = az_groups.election_nominations_create(0, accounts.charlie);
+            assert_eq!(result, Err(AZGroupsError::NotAMember));
+            // = when caller is a member
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // == when candidate is not a member
+            // == * it raises an error
+            result = az_groups.election_nominations_create(0, accounts.bob);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Candidate must be a member".to_string()
+                ))
+            );
+            // == when candidate is a member
+            // == * it adds the candidate
+            result = az_groups.election_nominations_create(0, accounts.charlie);
+            assert_eq!(
+                result.unwrap().candidates,
+                vec![ElectionCandidate {
+                    user: accounts.charlie,
+                    votes: 0
+                }]
+            );
+            // == when candidate has already been nominated
+            // == * it raises an error
+            result = az_groups.election_nominations_create(0, accounts.charlie);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Candidate has already been taken".to_string()
+                ))
+            );
+            // == when nominations have closed
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(100);
+            // == * it raises an error
+            result = az_groups.election_nominations_create(0, accounts.charlie);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Nominations have closed".to_string()
+                ))
+            );
+        }
+
+        #[ink::test]
+        fn test_election_votes_create() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Member)
+                .unwrap();
+            az_groups.elections_create(0, 1, 100, 100, 1_000).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups
+                .election_nominations_create(0, accounts.charlie)
+                .unwrap();
+            // when voting has not started
+            // * it raises an error
+            let mut result = az_groups.election_votes_create(0, accounts.charlie);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Voting has not started".to_string()
+                ))
+            );
+            // when voting is open
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(100);
+            // = when candidate has not been nominated
+            // = * it raises an error
+            result = az_groups.election_votes_create(0, accounts.bob);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("ElectionCandidate".to_string()))
+            );
+            // = when candidate has been nominated
+            // = * it adds the vote to the candidate
+            result = az_groups.election_votes_create(0, accounts.charlie);
+            assert_eq!(result.unwrap().candidates[0].votes, 1);
+            // = when caller has already voted
+            // = * it raises an error
+            result = az_groups.election_votes_create(0, accounts.charlie);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Vote has already been taken".to_string()
+                ))
+            );
+            // when voting has ended
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(200);
+            // * it raises an error
+            result = az_groups.election_votes_create(0, accounts.charlie);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Voting has ended".to_string()
+                ))
+            );
+        }
+
+        #[ink::test]
+        fn test_elections_close() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            let candidates: [AccountId; 3] = [accounts.charlie, accounts.django, accounts.eve];
+            for user in candidates {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(user);
+                az_groups.group_users_create(0).unwrap();
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+                az_groups.group_users_update(0, user, Role::Member).unwrap();
+            }
+            az_groups.elections_create(0, 2, 100, 100, 1_000).unwrap();
+            for user in candidates {
+                az_groups.election_nominations_create(0, user).unwrap();
+            }
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(100);
+            for (voter, candidate) in [
+                (accounts.bob, accounts.eve),
+                (accounts.charlie, accounts.eve),
+                (accounts.django, accounts.django),
+                (accounts.eve, accounts.charlie),
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(voter);
+                az_groups.election_votes_create(0, candidate).unwrap();
+            }
+            // when election does not exist
+            // * it raises an error
+            let mut result = az_groups.elections_close(1);
+            assert_eq!(result, Err(AZGroupsError::NotFound("Election".to_string())));
+            // when voting has not ended
+            // * it raises an error
+            result = az_groups.elections_close(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Voting has not ended".to_string()
+                ))
+            );
+            // when voting has ended
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(200);
+            // * it makes the top candidates admins for the term (ties go to the earlier nomination)
+            result = az_groups.elections_close(0);
+            assert_eq!(
+                result.unwrap().elected,
+                vec![accounts.eve, accounts.charlie]
+            );
+            for user in [accounts.eve, accounts.charlie] {
+                assert_eq!(
+                    az_groups.group_users_show(0, user).unwrap().role,
+                    Role::Admin
+                );
+                assert_eq!(az_groups.group_user_terms_show(0, user), Ok(1_200));
+            }
+            assert_eq!(
+                az_groups.group_users_show(0, accounts.django).unwrap().role,
+                Role::Member
+            );
+            // when election has already been closed
+            // * it raises an error
+            result = az_groups.elections_close(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Election has already been closed".to_string()
+                ))
+            );
+            // when group has a promotion delay
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups.group_promotion_delays_update(0, 50).unwrap();
+            az_groups.elections_create(0, 1, 100, 100, 1_000).unwrap();
+            az_groups
+                .election_nominations_create(1, accounts.django)
+                .unwrap();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(300);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            az_groups.election_votes_create(1, accounts.django).unwrap();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(400);
+            // * it queues the elected candidates as pending promotions
+            result = az_groups.elections_close(1);
+            assert_eq!(result.unwrap().elected, vec![accounts.django]);
+            assert_eq!(
+                az_groups.pending_promotions_index(0),
+                vec![PendingPromotion {
+                    group_id: 0,
+                    user: accounts.django,
+                    role: Role::Admin,
+                    promoter: accounts.django,
+                    executable_at: 450,
+                    election_id: Some(1),
+                }]
+            );
+            assert_eq!(
+                az_groups.group_users_show(0, accounts.django).unwrap().role,
+                Role::Member
+            );
+            // * the term starts when the promotion is executed
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(450);
+            az_groups
+                .pending_promotions_execute(0, accounts.django)
+                .unwrap();
+            assert_eq!(
+                az_groups.group_users_show(0, accounts.django).unwrap().role,
+                Role::Admin
+            );
+            assert_eq!(
+                az_groups.group_user_terms_show(0, accounts.django),
+                Ok(1_450)
+            );
+        }
+
+        #[ink::test]
+        fn test_elections_create() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            // when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            // * it raises an error
+            let mut result = az_groups.elections_create(0, 1, 100, 100, 1_000);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when seats is zero
+            // = * it raises an error
+            result = az_groups.elections_create(0, 0, 100, 100, 1_000);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Seats must be greater than zero".to_string()
+                ))
+            );
+            // = when a period is zero
+            // = * it raises an error
+            result = az_groups.elections_create(0, 1, 100, 100, 0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Periods must be greater than zero".to_string()
+                ))
+            );
+            // = when settings are valid
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(5);
+            // = * it creates the election
+            result = az_groups.elections_create(0, 1, 100, 100, 1_000);
+            let election: Election = Election {
+                id: 0,
+                group_id: 0,
+                seats: 1,
+                nominations_end_at: 105,
+                voting_ends_at: 205,
+                term: 1_000,
+                candidates: vec![],
+                elected: vec![],
+                closed: false,
+            };
+            assert_eq!(result, Ok(election.clone()));
+            assert_eq!(az_groups.elections_show(0), Ok(election));
+            // = when an election is in progress
+            // = * it raises an error
+            result = az_groups.elections_create(0, 1, 100, 100, 1_000);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Election is already in progress".to_string()
+                ))
+            );
+        }
+
+        #[ink::test]
+        fn test_group_domains_destroy() {
+            let (accounts, mut az_groups) = init();
+            // when group does not exist
+            // * it raises an error
+            let mut result = az_groups.group_domains_destroy(0);
+            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
+            // when group exists
+            az_groups
+                .groups_create(MOCK_GROUP_NAME.to_string())
+                .unwrap();
+            // = when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            // = * it raises an error
+            result = az_groups.group_domains_destroy(0);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // = when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // == when group doesn't have a domain
+            // == * it raises an error
+            result = az_groups.group_domains_destroy(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupDomain".to_string()))
+            );
+            // == when group has a domain
+            let mut group: Group = az_groups.groups_show(0).unwrap();
+            group.domain = Some("wave.azero".to_string());
+            az_groups.groups.insert(0, &group);
+            az_groups.group_id_by_domain.insert("wave.azero", &0);
+            // == * it unlinks the domain
+            az_groups.group_domains_destroy(0).unwrap();
+            assert_eq!(az_groups.groups_show(0).unwrap().domain, None);
+            // == * it releases the domain
+            assert!(az_groups.group_id_by_domain.get("wave.azero").is_none());
+        }
+
+        // Verifying ownership needs a cross contract call, which is covered by e2e_group_domains.
+        #[ink::test]
+        fn test_group_domains_update() {
+            let (accounts, mut az_groups) = init();
+            let domain: String = "wave.azero".to_string();
+            // when group does not exist
+            // * it raises an error
+            let mut result = az_groups.group_domains_update(0, domain.clone(), None);
+            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
+            // when group exists
+            az_groups
+                .groups_create(MOCK_GROUP_NAME.to_string())
+                .unwrap();
+            // = when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            // = * it raises an error
+            result = az_groups.group_domains_update(0, domain.clone(), None);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // = when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // == when domain is blank
+            // == * it raises an error
+            result = az_groups.group_domains_update(0, " ".to_string(), None);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Domain can't be blank".to_string()
+                ))
+            );
+            // == when domain is linked to another group
+            az_groups.group_id_by_domain.insert(domain.clone(), &1);
+            // == * it raises an error
+            result = az_groups.group_domains_update(0, " Wave.AZERO ".to_string(), None);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Domain has already been taken".to_string()
+                ))
+            );
+            az_groups.group_id_by_domain.remove(domain.clone());
+            // == when owner isn't the caller
+            // === when owner isn't part of the group
+            // === * it raises an error
+            result = az_groups.group_domains_update(0, domain.clone(), Some(accounts.django));
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupUser".to_string()))
+            );
+            // === when owner isn't a super admin of the group
+            // === * it raises an error
+            result = az_groups.group_domains_update(0, domain.clone(), Some(accounts.charlie));
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // == when domain registry isn't set
+            // == * it raises an error
+            result = az_groups.group_domains_update(0, domain, None);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Domain registry isn't set".to_string()
+                ))
+            );
+        }
+
+        #[ink::test]
+        fn test_group_governances_update() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            // when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            // * it raises an error
+            let mut result = az_groups.group_governances_update(0, 2, 50, 1_000);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when quorum is zero
+            // = * it raises an error
+            result = az_groups.group_governances_update(0, 0, 50, 1_000);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Quorum must be greater than zero".to_string()
+                ))
+            );
+            // = when threshold is greater than 100
+            // = * it raises an error
+            result = az_groups.group_governances_update(0, 2, 101, 1_000);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Threshold must be between 1 and 100".to_string()
+                ))
+            );
+            // = when voting period is zero
+            // = * it raises an error
+            result = az_groups.group_governances_update(0, 2, 50, 0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Voting period must be greater than zero".to_string()
+                ))
+            );
+            // = when settings are valid
+            // = * it sets the governance of the group
+            result = az_groups.group_governances_update(0, 2, 50, 1_000);
+            let group_governance: GroupGovernance = GroupGovernance {
+                quorum: 2,
+                threshold: 50,
+                voting_period: 1_000,
+            };
+            assert_eq!(result, Ok(group_governance.clone()));
+            assert_eq!(az_groups.group_governances_show(0), Ok(group_governance));
+        }
+
+        #[ink::test]
+        fn test_group_impeachments_update() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            // when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            // * it raises an error
+            let mut result = az_groups.group_impeachments_update(0, 50, 75, 100);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when admin threshold is zero or more than 100
+            // = * it raises an error
+            for admin_threshold in [0, 101] {
+                result = az_groups.group_impeachments_update(0, admin_threshold, 0, 100);
+                assert_eq!(
+                    result,
+                    Err(AZGroupsError::UnprocessableEntity(
+                        "Admin threshold must be between 1 and 100".to_string()
+                    ))
+                );
+            }
+            // = when super admin threshold is below the admin threshold or more than 100
+            // = * it raises an error
+            for super_admin_threshold in [49, 101] {
+                result = az_groups.group_impeachments_update(0, 50, super_admin_threshold, 100);
+                assert_eq!(
+                    result,
+                    Err(AZGroupsError::UnprocessableEntity(
+                        "Super admin threshold must be 0 or between the admin threshold and 100"
+                            .to_string()
+                    ))
+                );
+            }
+            // = when window is zero
+            // = * it raises an error
+            result = az_groups.group_impeachments_update(0, 50, 75, 0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Window must be greater than zero".to_string()
+                ))
+            );
+            // = when settings are valid
+            // = * it sets the group impeachment
+            result = az_groups.group_impeachments_update(0, 50, 75, 100);
+            let group_impeachment: GroupImpeachment = GroupImpeachment {
+                admin_threshold: 50,
+                super_admin_threshold: 75,
+                window: 100,
+            };
+            assert_eq!(result, Ok(group_impeachment.clone()));
+            assert_eq!(az_groups.group_impeachments_show(0), Ok(group_impeachment));
+            // == when super admin threshold is zero
+            // == * it sets the group impeachment
+            result = az_groups.group_impeachments_update(0, 50, 0, 100);
+            assert_eq!(result.unwrap().super_admin_threshold, 0);
+        }
+
+        #[ink::test]
+        fn test_group_join_conditions_destroy() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            // when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            // * it raises an error
+            let mut result = az_groups.group_join_conditions_destroy(0);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when group join condition does not exist
+            // = * it raises an error
+            result = az_groups.group_join_conditions_destroy(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupJoinCondition".to_string()))
+            );
+            // = when group join condition exists
+            az_groups
+                .group_join_conditions_update(
+                    0,
+                    accounts.django,
+                    JoinConditionPolicy::AllowApplication,
+                )
+                .unwrap();
+            // = * it removes the group join condition
+            az_groups.group_join_conditions_destroy(0).unwrap();
+            assert!(az_groups.group_join_conditions.get(0).is_none());
+        }
+
+        #[ink::test]
+        fn test_group_join_conditions_show() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            // when group join condition does not exist
+            // * it raises an error
+            let result = az_groups.group_join_conditions_show(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupJoinCondition".to_string()))
+            );
+            // when group join condition exists
+            // * it returns the group join condition
+            let group_join_condition: GroupJoinCondition = az_groups
+                .group_join_conditions_update(0, accounts.django, JoinConditionPolicy::AutoApprove)
+                .unwrap();
+            assert_eq!(
+                az_groups.group_join_conditions_show(0).unwrap(),
+                group_join_condition
+            );
+        }
+
+        #[ink::test]
+        fn test_group_join_conditions_update() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            // when group does not exist
+            // * it raises an error
+            let mut result = az_groups.group_join_conditions_update(
+                0,
+                accounts.django,
+                JoinConditionPolicy::AllowApplication,
+            );
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupUser".to_string()))
+            );
+            // when group exists
+            az_groups.groups_create(group_name).unwrap();
+            // = when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            // = * it raises an error
+            result = az_groups.group_join_conditions_update(
+                0,
+                accounts.django,
+                JoinConditionPolicy::AllowApplication,
+            );
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // = when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = * it sets the group join condition
+            result = az_groups.group_join_conditions_update(
+                0,
+                accounts.django,
+                JoinConditionPolicy::AllowApplication,
+            );
+            assert_eq!(
+                result.unwrap(),
+                GroupJoinCondition {
+                    address: accounts.django,
+                    policy: JoinConditionPolicy::AllowApplication
+                }
+            );
+            // = * it replaces an existing group join condition
+            result = az_groups.group_join_conditions_update(
+                0,
+                accounts.eve,
+                JoinConditionPolicy::AutoApprove,
+            );
+            assert_eq!(
+                az_groups.group_join_conditions.get(0).unwrap(),
+                result.unwrap()
+            );
+        }
+
+        #[ink::test]
+        fn test_group_metadata_update() {
+            let (accounts, mut az_groups) = init();
+            let metadata: GroupMetadata = GroupMetadata {
+                description: Some("Aleph Zero builders".to_string()),
+                website: Some("https://alephzero.org".to_string()),
+                logo_cid: Some(
+                    "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_string(),
+                ),
+                socials: vec![GroupSocial {
+                    platform: "x".to_string(),
+                    handle: "@aleph_zero".to_string(),
+                }],
+            };
+            // when group does not exist
+            // * it raises an error
+            let mut result = az_groups.group_metadata_update(0, metadata.clone());
+            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
+            // when group exists
+            az_groups
+                .groups_create(MOCK_GROUP_NAME.to_string())
+                .unwrap();
+            // * it has empty metadata
+            assert_eq!(
+                az_groups.group_metadata_show(0).unwrap(),
+                GroupMetadata::default()
+            );
+            // = when caller doesn't have the update metadata capability
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Admin)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // = * it raises an error
+            result = az_groups.group_metadata_update(0, metadata.clone());
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // = when caller has the update metadata capability
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_user_capabilities_update(0, accounts.charlie, CAPABILITY_UPDATE_METADATA)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // == when metadata is too large
+            // == * it raises an error
+            let social: GroupSocial = metadata.socials[0].clone();
+            for (invalid_metadata, message) in [
+                (
+                    GroupMetadata {
+                        description: Some("a".repeat(501)),
+                        ..metadata.clone()
+                    },
+                    "Description is too long",
+                ),
+                (
+                    GroupMetadata {
+                        website: Some("a".repeat(201)),
+                        ..metadata.clone()
+                    },
+                    "Website is too long",
+                ),
+                (
+                    GroupMetadata {
+                        logo_cid: Some("a".repeat(101)),
+                        ..metadata.clone()
+                    },
+                    "Logo CID is too long",
+                ),
+                (
+                    GroupMetadata {
+                        socials: vec![social.clone(); 6],
+                        ..metadata.clone()
+                    },
+                    "Socials limit reached",
+                ),
+                (
+                    GroupMetadata {
+                        socials: vec![GroupSocial {
+                            handle: "a".repeat(65),
+                            ..social
+                        }],
+                        ..metadata.clone()
+                    },
+                    "Social is too long",
+                ),
+            ] {
+                result = az_groups.group_metadata_update(0, invalid_metadata);
+                assert_eq!(
+                    result,
+                    Err(AZGroupsError::UnprocessableEntity(message.to_string()))
+                );
+            }
+            // == when metadata is valid
+            // == * it updates the metadata
+            result = az_groups.group_metadata_update(0, metadata.clone());
+            assert_eq!(result.unwrap(), metadata);
+            assert_eq!(az_groups.group_metadata_show(0).unwrap(), metadata);
+            // == * it replaces all of the metadata
+            result = az_groups.group_metadata_update(0, GroupMetadata::default());
+            assert_eq!(result.unwrap(), GroupMetadata::default());
+            assert_eq!(
+                az_groups.group_metadata_show(0).unwrap(),
+                GroupMetadata::default()
+            );
+        }
+
+        #[ink::test]
+        fn test_group_multisigs_update() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            // when caller is not a super admin
+            // * it raises an error
+            let mut result = az_groups.group_multisigs_update(0, 2);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when confirmations is zero
+            // = * it raises an error
+            result = az_groups.group_multisigs_update(0, 0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Confirmations must be greater than zero".to_string()
+                ))
+            );
+            // = when confirmations is more than the number of super admins
+            // = * it raises an error
+            result = az_groups.group_multisigs_update(0, 2);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Confirmations can't be more than the number of super admins".to_string()
+                ))
+            );
+            // = when group does not have a multisig
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::SuperAdmin)
+                .unwrap();
+            // = * it sets the confirmations
+            result = az_groups.group_multisigs_update(0, 2);
+            assert_eq!(result, Ok(2));
+            assert_eq!(az_groups.group_multisigs_show(0), 2);
+            // = when group has a multisig
+            // = * it creates a pending operation instead
+            result = az_groups.group_multisigs_update(0, 1);
+            assert_eq!(result, Ok(2));
+            assert_eq!(
+                az_groups.pending_operations_index(0),
+                vec![PendingOperation {
+                    id: 0,
+                    group_id: 0,
+                    proposer: accounts.bob,
+                    action: PendingOperationAction::GroupMultisigsUpdate { confirmations: 1 },
+                    confirmations: vec![accounts.bob],
+                }]
+            );
+            // == when caller has reached the pending operation limit
+            for _ in 1..PENDING_OPERATIONS_LIMIT {
+                az_groups.group_multisigs_update(0, 1).unwrap();
+            }
+            // == * it raises an error
+            result = az_groups.group_multisigs_update(0, 1);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Pending operation limit reached".to_string()
+                ))
+            );
+            // == * it doesn't stop other super admins from proposing
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            result = az_groups.group_multisigs_update(0, 1);
+            assert_eq!(result, Ok(2));
+            assert_eq!(
+                az_groups.pending_operations_index(0).len(),
+                PENDING_OPERATIONS_LIMIT as usize + 1
+            );
+        }
+
+        #[ink::test]
+        fn test_group_name_cooldowns_update() {
+            let (accounts, mut az_groups) = init();
+            // when caller is not the owner
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // * it raises an error
+            let mut result = az_groups.group_name_cooldowns_update(5);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is the owner
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // * it updates the cooldown
+            result = az_groups.group_name_cooldowns_update(5);
+            assert_eq!(result.unwrap(), 5);
+            assert_eq!(az_groups.group_name_cooldowns_show(), 5);
+            // * it applies to names replaced afterwards
+            az_groups
+                .groups_create(MOCK_GROUP_NAME.to_string())
+                .unwrap();
+            az_groups
+                .groups_update(0, "King Kong".to_string(), true)
+                .unwrap();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(5);
+            assert_eq!(
+                az_groups.groups_find_by_name(MOCK_GROUP_NAME.to_string()),
+                Err(AZGroupsError::NotFound("Group".to_string()))
+            );
+        }
+
+        #[ink::test]
+        fn test_group_name_rules_update() {
+            let (accounts, mut az_groups) = init();
+            // * it has default rules
+            assert_eq!(
+                az_groups.group_name_rules_show(),
+                GroupNameRules {
+                    max_bytes: 128,
+                    max_chars: 64,
+                    charset: GroupNameCharset::Letters,
+                }
+            );
+            // when caller is not the owner
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // * it raises an error
+            let mut result = az_groups.group_name_rules_update(10, 10, GroupNameCharset::Ascii);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is the owner
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when a limit is zero
+            // = * it raises an error
+            for (max_bytes, max_chars) in [(0, 10), (10, 0)] {
+                result = az_groups.group_name_rules_update(
+                    max_bytes,
+                    max_chars,
+                    GroupNameCharset::Ascii,
+                );
+                assert_eq!(
+                    result,
+                    Err(AZGroupsError::UnprocessableEntity(
+                        "Limits must be greater than zero".to_string()
+                    ))
+                );
+            }
+            // = when limits are greater than zero
+            // = * it sets the rules
+            result = az_groups.group_name_rules_update(12, 8, GroupNameCharset::Ascii);
+            let rules: GroupNameRules = GroupNameRules {
+                max_bytes: 12,
+                max_chars: 8,
+                charset: GroupNameCharset::Ascii,
+            };
+            assert_eq!(result, Ok(rules.clone()));
+            assert_eq!(az_groups.group_name_rules_show(), rules);
+            // = * it applies the rules to new names
+            for (name, message) in [
+                ("Waves Waves", "Name has too many characters"),
+                (
+                    "\u{00E9}\u{00E9}\u{00E9}\u{00E9}\u{00E9}\u{00E9}\u{00E9}",
+                    "Name is too long",
+                ),
+                ("\u{00E9}", "Name contains invalid characters"),
+            ] {
+                assert_eq!(
+                    az_groups.groups_create(name.to_string()),
+                    Err(AZGroupsError::UnprocessableEntity(message.to_string()))
+                );
+            }
+            assert!(az_groups.groups_create("Wave-1&2".to_string()).is_ok());
+            // = * it allows anything that survives formatting when charset is any
+            az_groups
+                .group_name_rules_update(128, 64, GroupNameCharset::Any)
+                .unwrap();
+            assert!(az_groups.groups_create("\u{1F30A}".to_string()).is_ok());
+        }
+
+        #[ink::test]
+        fn test_group_observers_create() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            // when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            // * it raises an error
+            let mut result = az_groups.group_observers_create(0, accounts.django);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when observer is not registered
+            // = * it adds the observer to the group
+            result = az_groups.group_observers_create(0, accounts.django);
+            assert_eq!(result.unwrap(), vec![accounts.django]);
+            assert_eq!(az_groups.group_observers_index(0), vec![accounts.django]);
+            // = when observer is already registered
+            // = * it raises an error
+            result = az_groups.group_observers_create(0, accounts.django);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Group observer has already been taken".to_string()
+                ))
+            );
+            // = when group observer limit has been reached
+            az_groups
+                .group_observers
+                .insert(0, &vec![AccountId::from([0; 32]); GROUP_OBSERVERS_LIMIT]);
+            // = * it raises an error
+            result = az_groups.group_observers_create(0, accounts.eve);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Group observer limit reached".to_string()
+                ))
+            );
+        }
+
+        #[ink::test]
+        fn test_group_observers_destroy() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_observers_create(0, accounts.django)
+                .unwrap();
+            az_groups.group_observers_create(0, accounts.eve).unwrap();
+            // when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // * it raises an error
+            let mut result = az_groups.group_observers_destroy(0, accounts.django);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when observer is not registered
+            // = * it raises an error
+            result = az_groups.group_observers_destroy(0, accounts.frank);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupObserver".to_string()))
+            );
+            // = when observer is registered
+            // = * it removes the observer from the group
+            result = az_groups.group_observers_destroy(0, accounts.django);
+            assert_eq!(result.unwrap(), vec![accounts.eve]);
+            assert_eq!(az_groups.group_observers_index(0), vec![accounts.eve]);
+        }
+
+        #[ink::test]
+        fn test_group_observers_index() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            // when group has no observers
+            // * it returns an empty list
+            assert!(az_groups.group_observers_index(0).is_empty());
+            // when group has observers
+            // * it returns the observers in the order they were registered
+            az_groups.groups_create(group_name).unwrap();
+            az_groups.group_observers_create(0, accounts.eve).unwrap();
+            az_groups
+                .group_observers_create(0, accounts.django)
+                .unwrap();
+            assert_eq!(
+                az_groups.group_observers_index(0),
+                vec![accounts.eve, accounts.django]
+            );
+        }
+
+        #[ink::test]
+        fn test_group_promotion_delays_update() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            // when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            // * it raises an error
+            let mut result = az_groups.group_promotion_delays_update(0, 100);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // * it sets the delay
+            result = az_groups.group_promotion_delays_update(0, 100);
+            assert_eq!(result, Ok(100));
+            assert_eq!(az_groups.group_promotion_delays_show(0), 100);
+        }
+
+        #[ink::test]
+        fn test_group_recoveries_update() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            // when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            // * it raises an error
+            let mut result = az_groups.group_recoveries_update(0, 1_000, 100);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when inactivity period is zero
+            // = * it raises an error
+            result = az_groups.group_recoveries_update(0, 0, 100);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Inactivity period must be greater than zero".to_string()
+                ))
+            );
+            // = when inactivity period is greater than zero
+            // = * it sets the recovery settings
+            result = az_groups.group_recoveries_update(0, 1_000, 100);
+            let group_recovery: GroupRecovery = GroupRecovery {
+                inactivity_period: 1_000,
+                challenge_period: 100,
+            };
+            assert_eq!(result, Ok(group_recovery.clone()));
+            assert_eq!(az_groups.group_recoveries_show(0), Ok(group_recovery));
+        }
+
+        #[ink::test]
+        fn test_group_role_capabilities_update() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            // * it defaults to the role ladder
+            assert_eq!(az_groups.group_role_capabilities_show(0, Role::Member), 0);
+            assert_eq!(
+                az_groups.group_role_capabilities_show(0, Role::Admin),
+                CAPABILITY_APPROVE_APPLICANTS
+                    | CAPABILITY_BAN
+                    | CAPABILITY_KICK
+                    | CAPABILITY_MANAGE_ROLES
+                    | CAPABILITY_UPDATE_WEIGHTS
+            );
+            assert_eq!(
+                az_groups.group_role_capabilities_show(0, Role::SuperAdmin),
+                CAPABILITIES_ALL
+            );
+            // when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            // * it raises an error
+            let mut result =
+                az_groups.group_role_capabilities_update(0, Role::Admin, CAPABILITY_KICK);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when role is not member or admin
+            // = * it raises an error
+            for role in [Role::Banned, Role::Applicant, Role::SuperAdmin] {
+                result = az_groups.group_role_capabilities_update(0, role, CAPABILITY_KICK);
+                assert_eq!(
+                    result,
+                    Err(AZGroupsError::UnprocessableEntity(
+                        "Role must be member or admin".to_string()
+                    ))
+                );
+            }
+            // = when role is member or admin
+            // == when capabilities are invalid
+            // == * it raises an error
+            result = az_groups.group_role_capabilities_update(0, Role::Admin, 1 << 31);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Capabilities are invalid".to_string()
+                ))
+            );
+            // == when capabilities are valid
+            // == * it sets the capabilities
+            result = az_groups.group_role_capabilities_update(0, Role::Admin, CAPABILITY_KICK);
+            assert_eq!(result, Ok(CAPABILITY_KICK));
+            assert_eq!(
+                az_groups.group_role_capabilities_show(0, Role::Admin),
+                CAPABILITY_KICK
+            );
+            // == * it applies to users with the role
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Admin)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            az_groups.group_users_create(0).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                az_groups.group_users_update(0, accounts.django, Role::Member),
+                Err(AZGroupsError::Unauthorised)
+            );
+            az_groups.group_users_destroy(0, accounts.django).unwrap();
+            // == when admins are given the update group capability
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_role_capabilities_update(0, Role::Admin, CAPABILITY_UPDATE_GROUP)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // == * it lets them update the group
+            assert!(az_groups
+                .groups_update(0, "Renamed".to_string(), true)
+                .is_ok());
+        }
+
+        #[ink::test]
+        fn test_group_roles_create() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            // when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            // * it raises an error
+            let mut result =
+                az_groups.group_roles_create(0, "Auditor".to_string(), 10, Role::Member);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when base is not member or admin
+            // = * it raises an error
+            result = az_groups.group_roles_create(0, "Auditor".to_string(), 10, Role::SuperAdmin);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Base must be member or admin".to_string()
+                ))
+            );
+            // = when name is blank
+            // = * it raises an error
+            result = az_groups.group_roles_create(0, " ".to_string(), 10, Role::Member);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Name can't be blank".to_string()
+                ))
+            );
+            // = when role is valid
+            // = * it creates the role
+            result = az_groups.group_roles_create(0, " Auditor ".to_string(), 10, Role::Member);
+            let auditor: GroupRole = GroupRole {
+                rank: 10,
+                name: "Auditor".to_string(),
+                base: Role::Member,
+            };
+            assert_eq!(result, Ok(auditor.clone()));
+            // = * it orders the roles by rank
+            az_groups
+                .group_roles_create(0, "Lead".to_string(), 20, Role::Admin)
+                .unwrap();
+            let core_contributor: GroupRole = az_groups
+                .group_roles_create(0, "Core Contributor".to_string(), 15, Role::Member)
+                .unwrap();
+            assert_eq!(
+                az_groups
+                    .group_roles_index(0)
+                    .into_iter()
+                    .map(|group_role| group_role.rank)
+                    .collect::<Vec<u8>>(),
+                vec![10, 15, 20]
+            );
+            assert_eq!(az_groups.group_roles_index(0)[1], core_contributor);
+            // = when rank has already been taken
+            // = * it raises an error
+            result = az_groups.group_roles_create(0, "Other".to_string(), 10, Role::Member);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Rank has already been taken".to_string()
+                ))
+            );
+            // = when name has already been taken
+            // = * it raises an error
+            result = az_groups.group_roles_create(0, "auditor".to_string(), 11, Role::Member);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Name has already been taken".to_string()
+                ))
+            );
+            // = when rank is out of order with the base role
+            // = * it raises an error
+            for (rank, base) in [(5, Role::Admin), (25, Role::Member)] {
+                result = az_groups.group_roles_create(0, "Other".to_string(), rank, base);
+                assert_eq!(
+                    result,
+                    Err(AZGroupsError::UnprocessableEntity(
+                        "Rank is out of order with the base role".to_string()
+                    ))
+                );
+            }
+            // = when group role limit has been reached
+            for rank in 0..7 {
+                az_groups
+                    .group_roles_create(0, rank.to_string(), rank, Role::Member)
+                    .unwrap();
+            }
+            // = * it raises an error
+            result = az_groups.group_roles_create(0, "Other".to_string(), 9, Role::Member);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Group role limit reached".to_string()
+                ))
+            );
+        }
+
+        #[ink::test]
+        fn test_group_roles_destroy() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Member)
+                .unwrap();
+            // when role does not exist
+            // * it raises an error
+            let mut result = az_groups.group_roles_destroy(0, 10);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupRole".to_string()))
+            );
+            // when role exists
+            az_groups
+                .group_roles_create(0, "Auditor".to_string(), 10, Role::Member)
+                .unwrap();
+            // = when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // = * it raises an error
+            result = az_groups.group_roles_destroy(0, 10);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // = when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // == when role is in use
+            az_groups
+                .group_user_roles_update(0, accounts.charlie, Some(10))
+                .unwrap();
+            // == * it raises an error
+            result = az_groups.group_roles_destroy(0, 10);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Group role is in use".to_string()
+                ))
+            );
+            // == when role is not in use
+            az_groups.group_users_destroy(0, accounts.charlie).unwrap();
+            // == * it removes the role
+            result = az_groups.group_roles_destroy(0, 10);
+            assert_eq!(result, Ok(()));
+            assert!(az_groups.group_roles_index(0).is_empty());
+        }
+
+        #[ink::test]
+        fn test_group_slugs_update() {
+            let (accounts, mut az_groups) = init();
+            // when group does not exist
+            // * it raises an error
+            let mut result = az_groups.group_slugs_update(0, "wave".to_string());
+            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
+            // when group exists
+            az_groups
+                .groups_create(MOCK_GROUP_NAME.to_string())
+                .unwrap();
+            az_groups.groups_create("Ripple".to_string()).unwrap();
+            // = when caller can't update the group
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            // = * it raises an error
+            result = az_groups.group_slugs_update(0, "wave".to_string());
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // = when caller can update the group
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // == when slug is invalid
+            // == * it raises an error
+            for (slug, message) in [
+                ("a".repeat(33), "Slug is too long"),
+                ("".to_string(), "Slug is invalid"),
+                ("Wave".to_string(), "Slug is invalid"),
+                ("the wave".to_string(), "Slug is invalid"),
+                ("-wave".to_string(), "Slug is invalid"),
+                ("the--wave".to_string(), "Slug is invalid"),
+                ("wave-".to_string(), "Slug is invalid"),
+                ("w\u{00E4}ve".to_string(), "Slug is invalid"),
+                ("ripple".to_string(), "Slug has already been taken"),
+            ] {
+                result = az_groups.group_slugs_update(0, slug);
+                assert_eq!(
+                    result,
+                    Err(AZGroupsError::UnprocessableEntity(message.to_string()))
+                );
+            }
+            // == when slug is valid
+            // == * it updates the slug
+            result = az_groups.group_slugs_update(0, "wave-2".to_string());
+            assert_eq!(result.unwrap(), "wave-2");
+            assert_eq!(az_groups.group_slugs_show(0).unwrap(), "wave-2");
+            assert_eq!(az_groups.group_id_by_slug.get("wave-2").unwrap(), 0);
+            // == * it releases the old slug
+            assert!(az_groups.group_id_by_slug.get("the-next-wave").is_none());
+            // == when group has a multisig
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::SuperAdmin)
+                .unwrap();
+            az_groups.group_multisigs_update(0, 2).unwrap();
+            // == * it creates a pending operation instead
+            result = az_groups.group_slugs_update(0, "the-wave".to_string());
+            assert_eq!(result.unwrap(), "wave-2");
+            assert_eq!(
+                az_groups.pending_operations_index(0)[0].action,
+                PendingOperationAction::GroupSlugsUpdate {
+                    slug: "the-wave".to_string()
+                }
+            );
+            // == * it updates the slug once confirmed
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            let id: u32 = az_groups.pending_operations_index(0)[0].id;
+            az_groups.pending_operations_confirm(id).unwrap();
+            assert_eq!(az_groups.group_slugs_show(0).unwrap(), "the-wave");
+        }
+
+        #[ink::test]
+        fn test_group_super_admin_activities_update() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(5);
+            az_groups.groups_create(group_name).unwrap();
+            // * it is set when the group is created
+            assert_eq!(az_groups.group_super_admin_activities_show(0), 5);
+            // when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(10);
+            // * it raises an error
+            let mut result = az_groups.group_super_admin_activities_update(0);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // * it records the activity
+            result = az_groups.group_super_admin_activities_update(0);
+            assert_eq!(result, Ok(10));
+            // * it is also recorded when the super admin makes other changes
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(15);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Member)
+                .unwrap();
+            assert_eq!(az_groups.group_super_admin_activities_show(0), 15);
+        }
+
+        #[ink::test]
+        fn test_group_tags_update() {
+            let (accounts, mut az_groups) = init();
+            for tag in ["auditor", "dao", "foundation"] {
+                az_groups.tags_create(tag.to_string()).unwrap();
+            }
+            // when group does not exist
+            // * it raises an error
+            let mut result = az_groups.group_tags_update(0, vec!["dao".to_string()]);
+            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
+            // when group exists
+            az_groups
+                .groups_create(MOCK_GROUP_NAME.to_string())
+                .unwrap();
+            // = when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            // = * it raises an error
+            result = az_groups.group_tags_update(0, vec!["dao".to_string()]);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // = when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // == when there are too many tags
+            // == * it raises an error
+            result = az_groups.group_tags_update(0, vec!["dao".to_string(); 6]);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Tags limit reached".to_string()
+                ))
+            );
+            // == when tags are duplicated
+            // == * it raises an error
+            result = az_groups.group_tags_update(0, vec!["dao".to_string(), " DAO".to_string()]);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Tags must be unique".to_string()
+                ))
+            );
+            // == when a tag is not in the vocabulary
+            // == * it raises an error
+            result = az_groups.group_tags_update(0, vec!["dao".to_string(), "defi".to_string()]);
+            assert_eq!(result, Err(AZGroupsError::NotFound("Tag".to_string())));
+            // == when tags are valid
+            // == * it sets the formatted tags
+            result = az_groups.group_tags_update(0, vec!["DAO".to_string(), "auditor".to_string()]);
+            assert_eq!(
+                result.unwrap(),
+                vec!["dao".to_string(), "auditor".to_string()]
+            );
+            assert_eq!(
+                az_groups.group_tags_index(0),
+                vec!["dao".to_string(), "auditor".to_string()]
+            );
+            // == * it adds the group to the tag index
+            assert_eq!(
+                az_groups.tag_groups_index("dao".to_string(), 0, 10),
+                vec![0]
+            );
+            assert_eq!(
+                az_groups.tag_groups_index("auditor".to_string(), 0, 10),
+                vec![0]
+            );
+            // == when a tag is removed
+            // == * it removes the group from the tag index
+            az_groups
+                .group_tags_update(0, vec!["auditor".to_string()])
+                .unwrap();
+            assert!(az_groups
+                .tag_groups_index("dao".to_string(), 0, 10)
+                .is_empty());
+            // == when a kept tag has been removed from the vocabulary
+            az_groups.tags_destroy("auditor".to_string()).unwrap();
+            // == * it keeps the tag
+            result = az_groups
+                .group_tags_update(0, vec!["auditor".to_string(), "foundation".to_string()]);
+            assert_eq!(
+                result.unwrap(),
+                vec!["auditor".to_string(), "foundation".to_string()]
+            );
+        }
+
+        #[ink::test]
+        fn test_group_treasuries_deposit() {
+            let (accounts, mut az_groups) = init();
+            // when group does not exist
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            // * it raises an error
+            let mut result = az_groups.group_treasuries_deposit(0);
+            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
+            // when group exists
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            // = when nothing is transferred
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+            // = * it raises an error
+            result = az_groups.group_treasuries_deposit(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Amount must be greater than zero".to_string()
+                ))
+            );
+            // = when something is transferred
+            // = * it adds the amount to the treasury (anyone can deposit)
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            result = az_groups.group_treasuries_deposit(0);
+            assert_eq!(result, Ok(100));
+            result = az_groups.group_treasuries_deposit(0);
+            assert_eq!(result, Ok(200));
+            assert_eq!(az_groups.group_treasuries_show(0), 200);
+        }
+
+        #[ink::test]
+        fn test_group_user_capabilities_update() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            for user in [accounts.charlie, accounts.django, accounts.eve] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(user);
+                az_groups.group_users_create(0).unwrap();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Member)
+                .unwrap();
+            az_groups
+                .group_users_update(0, accounts.django, Role::Member)
+                .unwrap();
+            let moderator: u32 = CAPABILITY_APPROVE_APPLICANTS | CAPABILITY_KICK;
+            // when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // * it raises an error
+            let mut result =
+                az_groups.group_user_capabilities_update(0, accounts.charlie, moderator);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when user is not a member
+            // = * it raises an error
+            result = az_groups.group_user_capabilities_update(0, accounts.eve, moderator);
+            assert_eq!(result, Err(AZGroupsError::NotAMember));
+            // = when user is a member
+            // == when capabilities are invalid
+            // == * it raises an error
+            result = az_groups.group_user_capabilities_update(0, accounts.charlie, 1 << 31);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Capabilities are invalid".to_string()
+                ))
+            );
+            // == when capabilities are valid
+            // == * it grants the capabilities on top of the role's
+            result = az_groups.group_user_capabilities_update(0, accounts.charlie, moderator);
+            assert_eq!(result, Ok(moderator));
+            assert_eq!(
+                az_groups.group_user_capabilities_show(0, accounts.charlie),
+                moderator
+            );
+            assert_eq!(
+                az_groups.group_user_capabilities_show(0, accounts.bob),
+                CAPABILITIES_ALL
+            );
+            // == * it lets the user use the capabilities
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                az_groups
+                    .group_users_update(0, accounts.eve, Role::Member)
+                    .unwrap()
+                    .role,
+                Role::Member
+            );
+            assert_eq!(
+                az_groups.group_users_update(0, accounts.eve, Role::Banned),
+                Err(AZGroupsError::Unauthorised)
+            );
+            assert_eq!(
+                az_groups.group_users_update(0, accounts.django, Role::Admin),
+                Err(AZGroupsError::Unauthorised)
+            );
+            az_groups.group_users_destroy(0, accounts.django).unwrap();
+            // == * it still checks the user's role against the target's
+            assert_eq!(
+                az_groups.group_users_destroy(0, accounts.bob),
+                Err(AZGroupsError::Unauthorised)
+            );
+            // == * it ignores the capabilities when the user isn't a member
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Applicant)
+                .unwrap();
+            assert_eq!(
+                az_groups.group_user_capabilities_show(0, accounts.charlie),
+                0
+            );
+            // == * it removes the capabilities when the user is destroyed
+            az_groups.group_users_destroy(0, accounts.charlie).unwrap();
+            assert!(az_groups
+                .group_user_capabilities
+                .get((0, accounts.charlie))
+                .is_none());
+        }
+
+        #[ink::test]
+        fn test_group_user_profiles_update() {
+            let (accounts, mut az_groups) = init();
+            az_groups
+                .groups_create(MOCK_GROUP_NAME.to_string())
+                .unwrap();
+            // when caller is not part of the group
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // * it raises an error
+            let mut result = az_groups.group_user_profiles_update(0, Some("Charlie".to_string()));
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupUser".to_string()))
+            );
+            // when caller is an applicant
+            az_groups.group_users_create(0).unwrap();
+            // * it raises an error
+            result = az_groups.group_user_profiles_update(0, Some("Charlie".to_string()));
+            assert_eq!(result, Err(AZGroupsError::NotAMember));
+            // when caller is a member
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Member)
+                .unwrap();
+            az_groups
+                .group_user_titles_update(0, accounts.charlie, Some("Lead Auditor".to_string()))
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // = when display name is too long
+            // = * it raises an error
+            result = az_groups.group_user_profiles_update(0, Some("a".repeat(65)));
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Display name is too long".to_string()
+                ))
+            );
+            // = when display name is valid
+            // = * it sets the formatted display name
+            // = * it keeps the title
+            let mut profile: GroupUserProfile = GroupUserProfile {
+                display_name: Some("Charlie Chaplin".to_string()),
+                title: Some("Lead Auditor".to_string()),
+            };
+            result =
+                az_groups.group_user_profiles_update(0, Some(" Charlie  Chaplin ".to_string()));
+            assert_eq!(result.unwrap(), profile);
+            assert_eq!(
+                az_groups
+                    .group_user_profiles_show(0, accounts.charlie)
+                    .unwrap(),
+                profile
+            );
+            // = when display name is blank
+            // = * it clears the display name
+            profile.display_name = None;
+            result = az_groups.group_user_profiles_update(0, Some(" ".to_string()));
+            assert_eq!(result.unwrap(), profile);
+        }
+
+        #[ink::test]
+        fn test_group_user_roles_update() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            for user in [accounts.charlie, accounts.django] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(user);
+                az_groups.group_users_create(0).unwrap();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Member)
+                .unwrap();
+            az_groups
+                .group_users_update(0, accounts.django, Role::Admin)
+                .unwrap();
+            let auditor: GroupRole = az_groups
+                .group_roles_create(0, "Auditor".to_string(), 10, Role::Member)
+                .unwrap();
+            az_groups
+                .group_roles_create(0, "Lead".to_string(), 20, Role::Admin)
+                .unwrap();
+            // when caller doesn't have the manage roles capability
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // * it raises an error
+            let mut result = az_groups.group_user_roles_update(0, accounts.django, Some(20));
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller has the manage roles capability
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // = when user has a higher role
+            // = * it raises an error
+            result = az_groups.group_user_roles_update(0, accounts.bob, None);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // = when user has the same role or less
+            // == when role does not exist
+            // == * it raises an error
+            result = az_groups.group_user_roles_update(0, accounts.charlie, Some(15));
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupRole".to_string()))
+            );
+            // == when user's role doesn't match the base
+            // == * it raises an error
+            result = az_groups.group_user_roles_update(0, accounts.charlie, Some(20));
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Role must match the group role's base".to_string()
+                ))
+            );
+            // == when user's role matches the base
+            // == * it gives the user the role
+            result = az_groups.group_user_roles_update(0, accounts.charlie, Some(10));
+            assert_eq!(result, Ok(Some(auditor.clone())));
+            assert_eq!(
+                az_groups.group_user_roles_show(0, accounts.charlie),
+                Some(auditor)
+            );
+            // == * it keeps validate_membership returning the built in role
+            assert_eq!(
+                az_groups.validate_membership(0, accounts.charlie),
+                Ok(Role::Member)
+            );
+            // == when rank is none
+            // == * it removes the user's role
+            result = az_groups.group_user_roles_update(0, accounts.charlie, None);
+            assert_eq!(result, Ok(None));
+            assert_eq!(az_groups.group_user_roles_show(0, accounts.charlie), None);
+            // == when the user's built in role changes
+            az_groups
+                .group_user_roles_update(0, accounts.charlie, Some(10))
+                .unwrap();
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Admin)
+                .unwrap();
+            // == * it removes the user's role
+            assert_eq!(az_groups.group_user_roles_show(0, accounts.charlie), None);
+            assert_eq!(az_groups.group_role_holders.get((0, 10)), Some(0));
+        }
+
+        #[ink::test]
+        fn test_group_user_terms_destroy() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Member)
+                .unwrap();
+            // when user does not have a term
+            // * it raises an error
+            let mut result = az_groups.group_user_terms_destroy(0, accounts.charlie);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupUserTerm".to_string()))
+            );
+            // when user has a term
+            az_groups.elections_create(0, 1, 100, 100, 1_000).unwrap();
+            az_groups
+                .election_nominations_create(0, accounts.charlie)
+                .unwrap();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(100);
+            az_groups
+                .election_votes_create(0, accounts.charlie)
+                .unwrap();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(200);
+            az_groups.elections_close(0).unwrap();
+            // = when term has not ended
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_199);
+            // = * it raises an error
+            result = az_groups.group_user_terms_destroy(0, accounts.charlie);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Term has not ended".to_string()
+                ))
+            );
+            // = when term has ended
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_200);
+            // = * the user is treated as a member before the term is destroyed
+            assert_eq!(
+                az_groups.validate_membership(0, accounts.charlie),
+                Ok(Role::Member)
+            );
+            assert_eq!(
+                az_groups.group_user_capabilities_show(0, accounts.charlie),
+                Role::Member.default_capabilities()
+            );
+            assert_eq!(az_groups.group_role_totals_show(0, Role::Admin), 0);
+            assert_eq!(az_groups.group_role_totals_show(0, Role::Member), 1);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // = * it makes the user a member again (anyone can call)
+            result = az_groups.group_user_terms_destroy(0, accounts.charlie);
+            assert_eq!(result.unwrap().role, Role::Member);
+            // = * it removes the term
+            assert!(az_groups
+                .group_user_terms_show(0, accounts.charlie)
+                .is_err());
+            assert_eq!(az_groups.group_role_totals_show(0, Role::Admin), 0);
+            assert_eq!(az_groups.group_role_totals_show(0, Role::Member), 1);
+            // when user's role is changed during their term
+            // * it removes the term
+            az_groups
+                .group_user_terms
+                .insert((0, accounts.charlie), &2_000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Banned)
+                .unwrap();
+            assert!(az_groups
+                .group_user_terms_show(0, accounts.charlie)
+                .is_err());
+        }
+
+        #[ink::test]
+        fn test_group_user_titles_update() {
+            let (accounts, mut az_groups) = init();
+            let title: Option<String> = Some("Lead Auditor".to_string());
+            az_groups
+                .groups_create(MOCK_GROUP_NAME.to_string())
+                .unwrap();
+            for user in [accounts.charlie, accounts.django] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(user);
+                az_groups.group_users_create(0).unwrap();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // when user is not a member
+            // * it raises an error
+            let mut result = az_groups.group_user_titles_update(0, accounts.charlie, title.clone());
+            assert_eq!(result, Err(AZGroupsError::NotAMember));
+            // when user is a member
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Member)
+                .unwrap();
+            // = when caller is not an admin
+            az_groups
+                .group_users_update(0, accounts.django, Role::Member)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // = * it raises an error
+            result = az_groups.group_user_titles_update(0, accounts.charlie, title.clone());
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // = when caller is an admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.django, Role::Admin)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // == when user has a higher role
+            // == * it raises an error
+            result = az_groups.group_user_titles_update(0, accounts.bob, title.clone());
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // == when title is too long
+            // == * it raises an error
+            result = az_groups.group_user_titles_update(0, accounts.charlie, Some("a".repeat(65)));
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Title is too long".to_string()
+                ))
+            );
+            // == when title is valid
+            // == * it sets the title
+            result = az_groups.group_user_titles_update(0, accounts.charlie, title.clone());
+            assert_eq!(
+                result.unwrap(),
+                GroupUserProfile {
+                    display_name: None,
+                    title
+                }
+            );
+        }
+
+        #[ink::test]
+        fn test_group_user_weights_update() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            for user in [accounts.charlie, accounts.django, accounts.eve] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(user);
+                az_groups.group_users_create(0).unwrap();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Admin)
+                .unwrap();
+            az_groups
+                .group_users_update(0, accounts.django, Role::Member)
+                .unwrap();
+            az_groups.group_weight_limits_update(0, 5).unwrap();
+            // * it starts everyone with a weight of 1
+            assert_eq!(
+                az_groups.group_users_show(0, accounts.eve).unwrap().weight,
+                1
+            );
+            // * it only counts members, admins and super admins in the total
+            assert_eq!(az_groups.group_weight_totals_show(0), 3);
+            // when user is not a member
+            // * it raises an error
+            let mut result = az_groups.group_user_weights_update(0, accounts.eve, 2);
+            assert_eq!(result, Err(AZGroupsError::NotAMember));
+            // when user is a member
+            // = when caller is the user
+            // = * it raises an error
+            result = az_groups.group_user_weights_update(0, accounts.bob, 2);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // = when caller doesn't have the update weights capability
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // = * it raises an error
+            result = az_groups.group_user_weights_update(0, accounts.charlie, 2);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_role_capabilities_update(0, Role::Admin, CAPABILITY_KICK)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            result = az_groups.group_user_weights_update(0, accounts.django, 2);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // = when caller has the update weights capability
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_role_capabilities_update(0, Role::Admin, Role::Admin.default_capabilities())
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // == when user has a higher role
+            // == * it raises an error
+            result = az_groups.group_user_weights_update(0, accounts.bob, 2);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // == when user has the same role or less
+            // === when weight is zero or above the limit
+            // === * it raises an error
+            for weight in [0, 6] {
+                result = az_groups.group_user_weights_update(0, accounts.django, weight);
+                assert_eq!(
+                    result,
+                    Err(AZGroupsError::UnprocessableEntity(
+                        "Weight must be between 1 and the group's weight limit".to_string()
+                    ))
+                );
+            }
+            // === when weight is within the limit
+            // === * it sets the weight
+            result = az_groups.group_user_weights_update(0, accounts.django, 5);
+            assert_eq!(result.unwrap().weight, 5);
+            // === * it updates the group's total weight
+            assert_eq!(az_groups.group_weight_totals_show(0), 7);
+            // === * it keeps the weight when the role changes
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.django, Role::Applicant)
+                .unwrap();
+            assert_eq!(az_groups.group_weight_totals_show(0), 2);
+            az_groups
+                .group_users_update(0, accounts.django, Role::Member)
+                .unwrap();
+            assert_eq!(az_groups.group_weight_totals_show(0), 7);
+            // === * it removes the weight from the total when the user is destroyed
+            az_groups.group_users_destroy(0, accounts.django).unwrap();
+            assert_eq!(az_groups.group_weight_totals_show(0), 2);
+        }
+
+        #[ink::test]
+        fn test_group_users_create() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            // when group with id does not exist
+            // * it raises an error
+            let mut result = az_groups.group_users_create(0);
+            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
+            // when group with id exists
+            az_groups.groups_create(group_name).unwrap();
+            // = when GroupUser exists
+            result = az_groups.group_users_create(0);
+            // = * it raises an error
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Group user has already been taken".to_string()
+                ))
+            );
+            // = when GroupUser doesn't exist
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            // = * it creates the group user with the role applicant
+            result = az_groups.group_users_create(0);
+            assert_eq!(result.unwrap().role, Role::Applicant);
+        }
+
+        #[ink::test]
+        fn test_group_users_destroy() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            //  when group with key exists
+            az_groups.groups_create(group_name.clone()).unwrap();
+            // = when caller does not have a group user for team
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            let mut result = az_groups.group_users_destroy(0, accounts.bob);
+            // = * it raises an error
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupUser".to_string()))
+            );
+            // = when caller has a group user for team
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // == when user does not have a group user for team
+            result = az_groups.group_users_destroy(0, accounts.charlie);
+            // == * it raises an error
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupUser".to_string()))
+            );
+            // == when user has a group user for team
+            // === when caller equals user
+            // ==== when role is super admin
+            // ==== * it raises an error
+            result = az_groups.group_users_destroy(0, accounts.bob);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // ==== when role is not super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // ===== when role is not banned
+            az_groups.group_users_create(0).unwrap();
+            // ===== * it destroys UserGroup
+            az_groups.group_users_destroy(0, accounts.charlie).unwrap();
+            assert!(az_groups.group_users.get((0, accounts.charlie)).is_none());
+            // ===== when role is banned
+            az_groups.group_users_create(0).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Banned)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // ===== * it raises an error
+            result = az_groups.group_users_destroy(0, accounts.charlie);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // === when caller does not equal user
+            // ==== when caller role is less than 3 (less than admin)
+            // ==== * it raises an error
+            result = az_groups.group_users_destroy(0, accounts.bob);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // ==== when caller role is greater than or equal to 3
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Admin)
+                .unwrap();
+            // ===== when user is banned
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            az_groups.group_users_create(0).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.django, Role::Banned)
+                .unwrap();
+            // ====== when caller doesn't have the ban capability
+            az_groups
+                .group_role_capabilities_update(0, Role::Admin, CAPABILITY_KICK)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // ====== * it raises an error
+            result = az_groups.group_users_destroy(0, accounts.django);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            assert!(az_groups.group_users.get((0, accounts.django)).is_some());
+            // ====== when caller has the ban capability
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_role_capabilities_update(0, Role::Admin, CAPABILITY_BAN)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // ====== * it destroys UserGroup
+            az_groups.group_users_destroy(0, accounts.django).unwrap();
+            assert!(az_groups.group_users.get((0, accounts.django)).is_none());
+            // ===== when caller's role is less than user's role
+            // ===== * it raises an error
+            result = az_groups.group_users_destroy(0, accounts.bob);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // ===== when caller's role is greater than or equal to user's role
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::SuperAdmin)
+                .unwrap();
+            az_groups
+                .group_user_titles_update(0, accounts.charlie, Some("Lead Auditor".to_string()))
+                .unwrap();
+            // ===== * it destroys UserGroup
+            az_groups.group_users_destroy(0, accounts.charlie).unwrap();
+            assert!(az_groups.group_users.get((0, accounts.charlie)).is_none());
+            // ===== * it clears the profile
+            assert!(az_groups
+                .group_user_profiles
+                .get((0, accounts.charlie))
+                .is_none());
+            // ===== when group has a multisig
+            for user in [accounts.charlie, accounts.django] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(user);
+                az_groups.group_users_create(0).unwrap();
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+                az_groups
+                    .group_users_update(0, user, Role::SuperAdmin)
+                    .unwrap();
+            }
+            az_groups.group_multisigs_update(0, 3).unwrap();
+            // ====== when user is a super admin
+            // ======= when the multisig would be left without enough super admins
+            // ======= * it raises an error
+            result = az_groups.group_users_destroy(0, accounts.charlie);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Multisig requires more super admins".to_string()
+                ))
+            );
+            // ======= when the multisig would be left with enough super admins
+            az_groups.group_multisigs.insert(0, &2);
+            // ======= * it creates a pending operation instead
+            az_groups.group_users_destroy(0, accounts.charlie).unwrap();
+            assert!(az_groups.group_users.get((0, accounts.charlie)).is_some());
+            assert_eq!(
+                az_groups.pending_operations_index(0)[0].action,
+                PendingOperationAction::GroupUserDestroy {
+                    user: accounts.charlie
+                }
+            );
+        }
+
+        #[ink::test]
+        fn test_group_users_update() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            // = when group with key exists
+            az_groups.groups_create(group_name).unwrap();
+            // == when caller equals user
+            // == * it raises an error
+            let mut result = az_groups.group_users_update(0, accounts.bob, Role::SuperAdmin);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // == when caller is different to user
+            // === when caller does not have a group user for team
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            result = az_groups.group_users_update(0, accounts.bob, Role::SuperAdmin);
+            // === * it raises an error
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupUser".to_string()))
+            );
+            // === when caller has a group user for team
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // ==== when caller's role is less than 3
+            let mut caller_group_user: GroupUser =
+                az_groups.group_users.get((0, accounts.bob)).unwrap();
+            caller_group_user.role = Role::Member;
+            az_groups
+                .group_users
+                .insert((0, accounts.bob), &caller_group_user);
+            // ==== * it raises an error
+            result = az_groups.group_users_update(0, accounts.bob, Role::Member);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // ==== when caller's role is 3 or more
+            caller_group_user.role = Role::Admin;
+            az_groups
+                .group_users
+                .insert((0, accounts.bob), &caller_group_user);
+            // ===== when user does not have a group user for team
+            result = az_groups.group_users_update(0, accounts.charlie, Role::SuperAdmin);
+            // ===== * it raises an error
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupUser".to_string()))
+            );
+            // ===== when user has a role with team
+            // ====== when caller's role is less than user's role
+            let mut user_group_user: GroupUser = GroupUser {
+                role: Role::SuperAdmin,
+                weight: 1,
+            };
+            az_groups
+                .group_users
+                .insert((0, accounts.charlie), &user_group_user);
+            // ====== * it raises an error
+            result = az_groups.group_users_update(0, accounts.charlie, Role::SuperAdmin);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // ====== when caller's role is greater than or equal to user's role
+            user_group_user = GroupUser {
+                role: Role::Admin,
+                weight: 1,
+            };
+            az_groups
+                .group_users
+                .insert((0, accounts.charlie), &user_group_user);
+            // ======= when new role is less than or equal to caller's role
+            // ======= * it updates the user's role
+            result = az_groups.group_users_update(0, accounts.charlie, Role::Admin);
+            assert_eq!(result.unwrap().role, Role::Admin);
+            // ======= when new role is greater than caller's role
+            // ======= * it raises an error
+            result = az_groups.group_users_update(0, accounts.charlie, Role::SuperAdmin);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // ======= when demoting a super admin and group has a multisig
+            caller_group_user.role = Role::SuperAdmin;
+            az_groups
+                .group_users
+                .insert((0, accounts.bob), &caller_group_user);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::SuperAdmin)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            az_groups.group_users_create(0).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.django, Role::SuperAdmin)
+                .unwrap();
+            az_groups.group_multisigs_update(0, 2).unwrap();
+            // ======= * it creates a pending operation instead
+            result = az_groups.group_users_update(0, accounts.charlie, Role::Member);
+            assert_eq!(result.unwrap().role, Role::SuperAdmin);
+            assert_eq!(
+                az_groups.pending_operations_index(0)[0].action,
+                PendingOperationAction::GroupUserUpdate {
+                    user: accounts.charlie,
+                    role: Role::Member
+                }
+            );
+            // ======= when group has a promotion delay
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            az_groups.group_users_create(0).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups.group_promotion_delays_update(0, 100).unwrap();
+            // ======== when promoting to admin or super admin
+            // ======== * it queues the promotion instead
+            result = az_groups.group_users_update(0, accounts.eve, Role::Admin);
+            assert_eq!(result.unwrap().role, Role::Applicant);
+            assert_eq!(az_groups.pending_promotions_index(0)[0].user, accounts.eve);
+            // ======== * it doesn't allow a second promotion to be queued
+            result = az_groups.group_users_update(0, accounts.eve, Role::SuperAdmin);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Pending promotion has already been taken".to_string()
+                ))
+            );
+            // ======== when demoting or banning
+            // ======== * it updates the role straight away
+            result = az_groups.group_users_update(0, accounts.eve, Role::Banned);
+            assert_eq!(result.unwrap().role, Role::Banned);
+            // ======== * it removes the pending promotion
+            assert!(az_groups.pending_promotions_index(0).is_empty());
+        }
+
+        #[ink::test]
+        fn test_group_verifications_create() {
+            let (accounts, mut az_groups) = init();
+            let evidence_hash: Hash = [1; 32].into();
+            az_groups.verifiers_create(accounts.django).unwrap();
+            // when group does not exist
+            // * it raises an error
+            let mut result = az_groups.group_verifications_create(0, evidence_hash, 10);
+            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
+            // when group exists
+            az_groups
+                .groups_create(MOCK_GROUP_NAME.to_string())
+                .unwrap();
+            // = when caller is not a verifier
+            // = * it raises an error
+            result = az_groups.group_verifications_create(0, evidence_hash, 10);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // = when caller is a verifier
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(5);
+            // == when expiry is not in the future
+            // == * it raises an error
+            result = az_groups.group_verifications_create(0, evidence_hash, 5);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Expiry must be in the future".to_string()
+                ))
+            );
+            // == when expiry is in the future
+            // == * it verifies the group
+            let verification: GroupVerification = GroupVerification {
+                verifier: accounts.django,
+                evidence_hash,
+                expires_at: 10,
+            };
+            result = az_groups.group_verifications_create(0, evidence_hash, 10);
+            assert_eq!(result.unwrap().verification, Some(verification.clone()));
+            assert_eq!(
+                az_groups.groups_show(0).unwrap().verification,
+                Some(verification)
+            );
+            // == * the verification is not shown once it expires
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(10);
+            assert_eq!(az_groups.groups_show(0).unwrap().verification, None);
+            // == * the verification is not shown once the verifier is removed
+            az_groups
+                .group_verifications_create(0, evidence_hash, 20)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups.verifiers_destroy(accounts.django).unwrap();
+            assert_eq!(az_groups.groups_show(0).unwrap().verification, None);
+        }
+
+        #[ink::test]
+        fn test_group_verifications_destroy() {
+            let (accounts, mut az_groups) = init();
+            az_groups.verifiers_create(accounts.django).unwrap();
+            az_groups.verifiers_create(accounts.eve).unwrap();
+            // when group does not exist
+            // * it raises an error
+            let mut result = az_groups.group_verifications_destroy(0);
+            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
+            // when group exists
+            az_groups
+                .groups_create(MOCK_GROUP_NAME.to_string())
+                .unwrap();
+            // = when caller is not a verifier
+            // = * it raises an error
+            result = az_groups.group_verifications_destroy(0);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // = when caller is a verifier
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            // == when group is not verified
+            // == * it raises an error
+            result = az_groups.group_verifications_destroy(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupVerification".to_string()))
+            );
+            // == when group is verified by another verifier
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            az_groups
+                .group_verifications_create(0, [1; 32].into(), 10)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            // == * it revokes the verification
+            az_groups.group_verifications_destroy(0).unwrap();
+            assert_eq!(az_groups.groups_show(0).unwrap().verification, None);
+        }
+
+        #[ink::test]
+        fn test_group_vouch_thresholds_update() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            // when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            // * it raises an error
+            let mut result = az_groups.group_vouch_thresholds_update(0, 2);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // * it sets the threshold
+            result = az_groups.group_vouch_thresholds_update(0, 2);
+            assert_eq!(result, Ok(2));
+            assert_eq!(az_groups.group_vouch_thresholds_show(0), 2);
+        }
+
+        #[ink::test]
+        fn test_group_weight_limits_update() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            // * it defaults to 1
+            assert_eq!(az_groups.group_weight_limits_show(0), 1);
+            // when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            // * it raises an error
+            let mut result = az_groups.group_weight_limits_update(0, 10);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when limit is zero
+            // = * it raises an error
+            result = az_groups.group_weight_limits_update(0, 0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Limit must be greater than zero".to_string()
+                ))
+            );
+            // = when limit is greater than zero
+            // = * it sets the limit
+            result = az_groups.group_weight_limits_update(0, 10);
+            assert_eq!(result, Ok(10));
+            assert_eq!(az_groups.group_weight_limits_show(0), 10);
+        }
+
+        #[ink::test]
+        fn test_groups_create() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            let key: String = names::group_name_key(&group_name);
+            // when group with key does not exist
+            // * it creates the group with the supplied name
+            // * it sets the group to enabled
+            // * it returns the created group
+            let mut result = az_groups.groups_create(group_name.clone());
+            let group = result.unwrap();
+            assert_eq!(group.name, group_name);
+            assert_eq!(group.enabled, true);
+            // * it creates and sets a new GroupUser with the caller as super admin
+            let group_user: GroupUser = az_groups.group_users.get((0, accounts.bob)).unwrap();
+            assert_eq!(group_user.role, Role::SuperAdmin);
+            // * it sets the group_id_by_name
+            assert_eq!(az_groups.group_id_by_name.get(key.clone()).unwrap(), 0);
+            // * it increases the groups total by one
+            assert_eq!(az_groups.groups_total, 1);
+            // * it derives the slug from the name
+            assert_eq!(az_groups.group_slugs_show(0).unwrap(), "the-next-wave");
+            assert_eq!(az_groups.group_id_by_slug.get("the-next-wave").unwrap(), 0);
+            // when group with key already exists
+            // * it raises an error
+            result = az_groups.groups_create(group_name.to_lowercase());
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Group has already been taken".to_string()
+                ))
+            );
+            // when name only differs by whitespace, invisible characters, compatibility forms or lookalikes
+            // * it raises an error
+            for name in [
+                "THE NEXT WAVE",
+                "The  Next   Wave",
+                "\tThe\nNext Wave ",
+                "The\u{00A0}Next\u{3000}Wave",
+                "The\u{200B} Next Wave",
+                "Th\u{200D}e Ne\u{2060}xt Wave",
+                "\u{FEFF}The Next Wave\u{00AD}",
+                "\u{202E}The Next Wave\u{202C}",
+                "The Next Wave\u{FE0F}",
+                "The Next\u{0007} Wave",
+                "Ｔｈｅ Ｎｅｘｔ Ｗａｖｅ",
+                "\u{0422}h\u{0435} N\u{0435}\u{0445}t W\u{0430}v\u{0435}",
+                "\u{03A4}he \u{039D}ext Wave",
+                "The Next Wav\u{0435}",
+                "The Next \u{1D5B6}ave",
+                "\u{1D413}he \u{2115}ext Wave",
+            ] {
+                result = az_groups.groups_create(name.to_string());
+                assert_eq!(
+                    result,
+                    Err(AZGroupsError::UnprocessableEntity(
+                        "Group has already been taken".to_string()
+                    )),
+                    "{:?}",
+                    name
+                );
+            }
+            // when name only contains whitespace and invisible characters
+            // * it raises an error
+            result = az_groups.groups_create("\u{200B} \u{3000}\u{FEFF}".to_string());
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Name can't be blank".to_string()
+                ))
+            );
+            // when name breaks the name rules
+            // * it raises an error
+            for (name, message) in [
+                ("a".repeat(65), "Name has too many characters"),
+                ("\u{0E01}".repeat(43), "Name is too long"),
+                (
+                    "Wave \u{1F30A}".to_string(),
+                    "Name contains invalid characters",
+                ),
+            ] {
+                result = az_groups.groups_create(name);
+                assert_eq!(
+                    result,
+                    Err(AZGroupsError::UnprocessableEntity(message.to_string()))
+                );
+            }
+            az_groups
+                .reserved_names_create("Aleph Zero".to_string())
+                .unwrap();
+            result = az_groups.groups_create("ALEPH Zer\u{043E}".to_string());
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Name is reserved".to_string()
+                ))
+            );
+            // when name is different
+            // * it stores the name with whitespace collapsed and invisible characters removed
+            result = az_groups.groups_create("  The   Next\u{200B}  Waves ".to_string());
+            assert_eq!(result.unwrap().name, "The Next Waves".to_string());
+            // = when the derived slug is taken
+            // = * it adds the group id to the slug
+            az_groups
+                .groups_create("Th\u{00E9} Next Wave.".to_string())
+                .unwrap();
+            assert_eq!(az_groups.group_slugs_show(2).unwrap(), "the-next-wave-2");
+            // = when the name only differs by how its accents are written
+            // = * it raises an error
+            result = az_groups.groups_create("The\u{0301} Next Wave.".to_string());
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Group has already been taken".to_string()
+                ))
+            );
+            // = when the slug with the group id is taken too
+            az_groups
+                .group_slugs_update(2, "the-next-wave-3".to_string())
+                .unwrap();
+            az_groups
+                .group_slugs_update(1, "the-next-wave-3-2".to_string())
+                .unwrap();
+            // = * it adds a counter after the group id until the slug is free
+            assert_eq!(
+                az_groups.group_slug_derive("The Next Wave", 3),
+                "the-next-wave-3-3"
+            );
+            // = when the name has no ASCII letters or digits
+            // = * it falls back to group
+            az_groups
+                .groups_create("\u{0E01}\u{0E02}".to_string())
+                .unwrap();
+            assert_eq!(az_groups.group_slugs_show(3).unwrap(), "group");
+            // = when the derived slug is too long
+            // = * it truncates the slug
+            az_groups.groups_create("Wave ".repeat(12)).unwrap();
+            assert_eq!(
+                az_groups.group_slugs_show(4).unwrap(),
+                "wave-wave-wave-wave-wave-wave-wa"
+            );
+            // when name only differs by a capital I for an l
+            az_groups.groups_create("PayPal".to_string()).unwrap();
+            // * it raises an error
+            result = az_groups.groups_create("PayPaI".to_string());
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Group has already been taken".to_string()
+                ))
+            );
+            // when groups_total is u32 max
+            az_groups.groups_total = u32::MAX;
+            // * it raises an error
+            result = az_groups.groups_create("XXXX".to_string());
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Group limit reached".to_string()
+                ))
+            );
+            // when group_name is blank
+            // * it raises an error
+            result = az_groups.groups_create(" ".to_string());
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Name can't be blank".to_string()
+                ))
+            );
+        }
+
+        #[ink::test]
+        fn test_groups_find_by_name() {
+            let (_accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            // when group with name does not exist
+            // * it raises an error
+            let mut result = az_groups.groups_find_by_name(group_name.clone());
+            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
+            // when group with name exists
+            az_groups.groups_create(group_name.clone()).unwrap();
+            // = when name with no matching key is provided
+            // = * it raises an error
+            result = az_groups.groups_find_by_name("asdf".to_string());
+            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
+            // = when name with a matching key is provided (case insensitive)
+            // = * it returns the group
+            result = az_groups.groups_find_by_name(group_name.to_uppercase());
+            assert_eq!(
+                result.unwrap(),
+                GroupNameLookup {
+                    group: az_groups.groups.get(0).unwrap(),
+                    redirected: false
+                }
+            );
+            // = when name with a matching key after normalization is provided
+            // = * it returns the group
+            for name in [
+                " The  Next\u{200B} Wave ",
+                "Ｔｈｅ Ｎｅｘｔ Ｗａｖｅ",
+                "\u{0422}h\u{0435} N\u{0435}\u{0445}t W\u{0430}v\u{0435}",
+            ] {
+                result = az_groups.groups_find_by_name(name.to_string());
+                assert_eq!(result.unwrap().group, az_groups.groups.get(0).unwrap());
+            }
+            // = when name is one of the group's previous names
+            az_groups
+                .groups_update(0, "King Kong".to_string(), true)
+                .unwrap();
+            // == when cooldown has not ended
+            // == * it returns the group as a redirect
+            result = az_groups.groups_find_by_name(group_name.clone());
+            assert_eq!(
+                result.unwrap(),
+                GroupNameLookup {
+                    group: az_groups.groups.get(0).unwrap(),
+                    redirected: true
+                }
+            );
+            // == when cooldown has ended
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                az_groups.group_name_cooldowns_show(),
+            );
+            // == * it raises an error
+            result = az_groups.groups_find_by_name(group_name.clone());
+            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
+            // == * the name can be taken by another group
+            az_groups.groups_create(group_name.clone()).unwrap();
+            result = az_groups.groups_find_by_name(group_name);
+            assert_eq!(result.unwrap().group.id, 1);
+        }
+
+        #[ink::test]
+        fn test_groups_find_by_slug() {
+            let (_accounts, mut az_groups) = init();
+            // when slug does not exist
+            // * it raises an error
+            let mut result = az_groups.groups_find_by_slug("the-next-wave".to_string());
+            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
+            // when slug exists
+            az_groups
+                .groups_create(MOCK_GROUP_NAME.to_string())
+                .unwrap();
+            // * it returns the group
+            result = az_groups.groups_find_by_slug("the-next-wave".to_string());
+            assert_eq!(result.unwrap(), az_groups.groups.get(0).unwrap());
+            // * it keeps the slug when the group is renamed
+            az_groups
+                .groups_update(0, "King Kong".to_string(), true)
+                .unwrap();
+            result = az_groups.groups_find_by_slug("the-next-wave".to_string());
+            assert_eq!(result.unwrap().name, "King Kong");
+        }
+
+        #[ink::test]
+        fn test_groups_update() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            let key: String = names::group_name_key(&group_name);
+            // when group with key does not exist
+            // * it raises an error
+            let mut result = az_groups.groups_update(0, group_name.clone(), true);
+            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
+            // when group with key exists
+            az_groups.groups_create(group_name.clone()).unwrap();
+            // = when caller is not part of group
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // = * it raises an error
+            result = az_groups.groups_update(0, group_name.clone(), true);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupUser".to_string()))
+            );
+            // = when caller is part of group
+            az_groups.group_users_create(0).unwrap();
+            // == when caller is not a super admin
+            // == * it raises an error
+            result = az_groups.groups_update(0, group_name.clone(), true);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // == when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // === when new_name is present
+            // ==== when new_name is empty blank
+            // ==== * it raises an error
+            result = az_groups.groups_update(0, " ".to_string(), false);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Name can't be blank".to_string()
+                ))
+            );
+            // ==== when new_name is available
+            // ==== * it updates the group
+            let mut new_name: String = "King Kong".to_string();
+            result = az_groups.groups_update(0, new_name.clone(), false);
+            assert_eq!(
+                result.unwrap(),
+                Group {
+                    id: 0,
+                    name: new_name.clone(),
+                    enabled: false,
+                    domain: None,
+                    verification: None
+                }
+            );
+            // ==== * it removes the old group_id_by_name map
+            assert!(az_groups.group_id_by_name.get(key).is_none());
+            // ==== * it create the new group_id_by_name map
+            assert_eq!(
+                az_groups
+                    .group_id_by_name
+                    .get(names::group_name_key(&new_name))
+                    .unwrap(),
+                0
+            );
+            // ==== * it records the old name in the group's name history
+            assert_eq!(
+                az_groups.group_name_histories_index(0),
+                vec![GroupNameChange {
+                    name: group_name.clone(),
+                    replaced_at: 0
+                }]
+            );
+            // ==== * it reserves the old name for the group during the cooldown
+            assert_eq!(
+                az_groups.groups_create(group_name.clone()),
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Group has already been taken".to_string()
+                ))
+            );
+            // ==== when new_name is taken
+            // ===== when new_name's key is the same as the original key
+            new_name = new_name.to_uppercase() + " ";
+            result = az_groups.groups_update(0, new_name.clone(), true);
+            // ===== * it updates
+            assert_eq!(
+                result.unwrap(),
+                Group {
+                    id: 0,
+                    name: names::format_group_name(new_name),
+                    enabled: true,
+                    domain: None,
+                    verification: None
+                }
+            );
+            // ===== when new_name's key is different from the original key
+            az_groups.group_id_by_name.insert("a".to_string(), &1);
+            // ===== * it raises an error
+            for name in ["A", " \u{200B}A ", "\u{0410}", "\u{FF21}"] {
+                result = az_groups.groups_update(0, name.to_string(), true);
+                assert_eq!(
+                    result,
+                    Err(AZGroupsError::UnprocessableEntity(
+                        "Group has already been taken".to_string()
+                    ))
+                );
+            }
+            // === when group has a multisig
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::SuperAdmin)
+                .unwrap();
+            az_groups.group_multisigs_update(0, 2).unwrap();
+            // ==== when only enabling
+            // ==== * it updates the group
+            result = az_groups.groups_update(0, "KING KONG".to_string(), true);
+            assert!(result.unwrap().enabled);
+            assert!(az_groups.pending_operations_index(0).is_empty());
+            // ==== when renaming or disabling
+            // ==== * it creates a pending operation instead
+            result = az_groups.groups_update(0, "Queen Kong".to_string(), false);
+            assert_eq!(result.unwrap().name, "KING KONG".to_string());
+            assert_eq!(
+                az_groups.pending_operations_index(0)[0].action,
+                PendingOperationAction::GroupsUpdate {
+                    name: "Queen Kong".to_string(),
+                    enabled: false
+                }
+            );
+        }
+
+        #[ink::test]
+        fn test_pending_operations_confirm() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            for user in [accounts.charlie, accounts.django, accounts.eve] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(user);
+                az_groups.group_users_create(0).unwrap();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            for user in [accounts.charlie, accounts.eve] {
+                az_groups
+                    .group_users_update(0, user, Role::SuperAdmin)
+                    .unwrap();
+            }
+            az_groups.group_multisigs_update(0, 2).unwrap();
+            // when pending operation does not exist
+            // * it raises an error
+            let mut result = az_groups.pending_operations_confirm(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("PendingOperation".to_string()))
+            );
+            // when pending operation exists
+            az_groups
+                .groups_update(0, "King Kong".to_string(), true)
+                .unwrap();
+            // = when caller is not a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // = * it raises an error
+            result = az_groups.pending_operations_confirm(0);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // = when caller has already confirmed
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = * it raises an error
+            result = az_groups.pending_operations_confirm(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Confirmation has already been taken".to_string()
+                ))
+            );
+            // = when confirmations reach the requirement
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // = * it executes the operation
+            result = az_groups.pending_operations_confirm(0);
+            assert_eq!(result.unwrap().confirmations.len(), 2);
+            assert_eq!(az_groups.groups_show(0).unwrap().name, "King Kong");
+            // = * it removes the pending operation
+            assert!(az_groups.pending_operations_index(0).is_empty());
+            assert!(az_groups.pending_operations.get(0).is_none());
+            // = when a confirmer is no longer a super admin
+            az_groups
+                .groups_update(0, "King Kong".to_string(), false)
+                .unwrap();
+            az_groups.group_users.insert(
+                (0, accounts.charlie),
+                &GroupUser {
+                    role: Role::Admin,
+                    weight: 1,
+                },
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = * their confirmation isn't counted
+            az_groups.pending_operations_confirm(1).unwrap();
+            assert!(az_groups.groups_show(0).unwrap().enabled);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            az_groups.pending_operations_confirm(1).unwrap();
+            assert!(!az_groups.groups_show(0).unwrap().enabled);
+        }
+
+        #[ink::test]
+        fn test_pending_operations_revoke() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            for user in [accounts.charlie, accounts.django] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(user);
+                az_groups.group_users_create(0).unwrap();
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+                az_groups
+                    .group_users_update(0, user, Role::SuperAdmin)
+                    .unwrap();
+            }
+            az_groups.group_multisigs_update(0, 3).unwrap();
+            // when pending operation does not exist
+            // * it raises an error
+            let mut result = az_groups.pending_operations_revoke(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("PendingOperation".to_string()))
+            );
+            // when pending operation exists
+            az_groups
+                .groups_update(0, "King Kong".to_string(), true)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.pending_operations_confirm(0).unwrap();
+            // = when caller has not confirmed
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // = * it raises an error
+            result = az_groups.pending_operations_revoke(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("Confirmation".to_string()))
+            );
+            // = when caller has confirmed
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // == when other confirmations remain
+            // == * it removes the confirmation
+            result = az_groups.pending_operations_revoke(0);
+            assert_eq!(result.unwrap().confirmations, vec![accounts.bob]);
+            assert_eq!(
+                az_groups.pending_operations_index(0)[0].confirmations,
+                vec![accounts.bob]
+            );
+            // == when no confirmations remain
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // == * it removes the pending operation
+            az_groups.pending_operations_revoke(0).unwrap();
+            assert!(az_groups.pending_operations_index(0).is_empty());
+            assert!(az_groups.pending_operations.get(0).is_none());
+        }
+
+        #[ink::test]
+        fn test_pending_promotions_destroy() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            az_groups.group_promotion_delays_update(0, 100).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            // when caller is not a super admin
+            // * it raises an error
+            let mut result = az_groups.pending_promotions_destroy(0, accounts.charlie);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is a super admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when pending promotion does not exist
+            // = * it raises an error
+            result = az_groups.pending_promotions_destroy(0, accounts.charlie);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("PendingPromotion".to_string()))
+            );
+            // = when pending promotion exists
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Admin)
+                .unwrap();
+            // = * it vetoes the promotion
+            result = az_groups.pending_promotions_destroy(0, accounts.charlie);
+            assert_eq!(result, Ok(()));
+            assert!(az_groups.pending_promotions_index(0).is_empty());
+            assert_eq!(
+                az_groups.pending_promotions_execute(0, accounts.charlie),
+                Err(AZGroupsError::NotFound("PendingPromotion".to_string()))
+            );
+        }
+
+        #[ink::test]
+        fn test_pending_promotions_execute() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            az_groups.group_promotion_delays_update(0, 100).unwrap();
+            for user in [accounts.charlie, accounts.django] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(user);
+                az_groups.group_users_create(0).unwrap();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // when pending promotion does not exist
+            // * it raises an error
+            let mut result = az_groups.pending_promotions_execute(0, accounts.charlie);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("PendingPromotion".to_string()))
+            );
+            // when pending promotion exists
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Admin)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // = when delay has not passed
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(99);
+            // = * it raises an error
+            result = az_groups.pending_promotions_execute(0, accounts.charlie);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Promotion delay has not passed".to_string()
+                ))
+            );
+            // = when delay has passed
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(100);
+            // == when promoter no longer has a high enough role
+            az_groups.group_users.insert(
+                (0, accounts.bob),
+                &GroupUser {
+                    role: Role::Member,
+                    weight: 1,
+                },
+            );
+            // == * it raises an error
+            result = az_groups.pending_promotions_execute(0, accounts.charlie);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // == when promoter has a high enough role
+            az_groups.group_users.insert(
+                (0, accounts.bob),
+                &GroupUser {
+                    role: Role::SuperAdmin,
+                    weight: 1,
+                },
+            );
+            // == * it promotes the user (anyone can execute)
+            result = az_groups.pending_promotions_execute(0, accounts.charlie);
+            assert_eq!(result.unwrap().role, Role::Admin);
+            assert_eq!(
+                az_groups
+                    .group_users_show(0, accounts.charlie)
+                    .unwrap()
+                    .role,
+                Role::Admin
+            );
+            // == * it removes the pending promotion
+            assert!(az_groups.pending_promotions_index(0).is_empty());
+        }
+
+        #[ink::test]
+        fn test_petition_signatures_create() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            for user in [
+                accounts.charlie,
+                accounts.django,
+                accounts.eve,
+                accounts.frank,
+            ] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(user);
+                az_groups.group_users_create(0).unwrap();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            for user in [accounts.django, accounts.eve, accounts.frank] {
+                az_groups.group_users_update(0, user, Role::Member).unwrap();
+            }
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Admin)
+                .unwrap();
+            az_groups.group_impeachments_update(0, 60, 60, 100).unwrap();
+            // when petition does not exist
+            // * it raises an error
+            let mut result = az_groups.petition_signatures_create(0);
+            assert_eq!(result, Err(AZGroupsError::NotFound("Petition".to_string())));
+            // when petition exists
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            az_groups
+                .petitions_create(0, accounts.charlie, PetitionAction::Demote)
+                .unwrap();
+            // = when caller is not a member
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            az_groups.group_users_create(0).unwrap();
+            // = * it raises an error
+            result = az_groups.petition_signatures_create(0);
+            assert_eq!(result, Err(AZGroupsError::NotAMember));
+            // = when caller is the target
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // = * it raises an error
+            result = az_groups.petition_signatures_create(0);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // = when caller has already signed
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // = * it raises an error
+            result = az_groups.petition_signatures_create(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Signature has already been taken".to_string()
+                ))
+            );
+            // = when petition has ended
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(100);
+            // = * it raises an error
+            result = az_groups.petition_signatures_create(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Petition has ended".to_string()
+                ))
+            );
+            // = when petition is open
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(99);
+            // == when threshold hasn't been reached
+            // == * it records the signature
+            let mut petition: Petition = az_groups.petition_signatures_create(0).unwrap();
+            assert_eq!(petition.signatures, 2);
+            assert_eq!(petition.status, PetitionStatus::Active);
+            assert!(az_groups
+                .petition_signatures
+                .get((0, accounts.eve))
+                .is_some());
+            // == when threshold is reached
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.frank);
+            // == * it carries out the action
+            petition = az_groups.petition_signatures_create(0).unwrap();
+            assert_eq!(petition.signatures, 3);
+            assert_eq!(petition.status, PetitionStatus::Executed);
+            assert_eq!(az_groups.petitions_show(0), Ok(petition));
+            assert_eq!(
+                az_groups
+                    .group_users_show(0, accounts.charlie)
+                    .unwrap()
+                    .role,
+                Role::Member
+            );
+            // === when the action can't be carried out
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            az_groups
+                .petitions_create(0, accounts.bob, PetitionAction::Remove)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            az_groups.petition_signatures_create(1).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.frank);
+            // === * it sets the status to failed
+            petition = az_groups.petition_signatures_create(1).unwrap();
+            assert_eq!(petition.status, PetitionStatus::Failed);
+            assert_eq!(
+                az_groups.group_users_show(0, accounts.bob).unwrap().role,
+                Role::SuperAdmin
+            );
+            // = when petition is not active
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = * it raises an error
+            result = az_groups.petition_signatures_create(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Petition is not active".to_string()
+                ))
+            );
+        }
+
+        #[ink::test]
+        fn test_petitions_create() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            for user in [accounts.charlie, accounts.django] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(user);
+                az_groups.group_users_create(0).unwrap();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Admin)
+                .unwrap();
+            az_groups
+                .group_users_update(0, accounts.django, Role::Member)
+                .unwrap();
+            // when caller is not a member
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            az_groups.group_users_create(0).unwrap();
+            // * it raises an error
+            let mut result =
+                az_groups.petitions_create(0, accounts.charlie, PetitionAction::Remove);
+            assert_eq!(result, Err(AZGroupsError::NotAMember));
+            // when caller is a member
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // = when group doesn't allow petitions
+            // = * it raises an error
+            result = az_groups.petitions_create(0, accounts.charlie, PetitionAction::Remove);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupImpeachment".to_string()))
+            );
+            // = when group allows petitions
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups.group_impeachments_update(0, 50, 0, 100).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // == when target is the caller
+            // == * it raises an error
+            result = az_groups.petitions_create(0, accounts.django, PetitionAction::Remove);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // == when target is not an admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // == * it raises an error
+            result = az_groups.petitions_create(0, accounts.django, PetitionAction::Remove);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // == when target is a super admin and super admins can't be petitioned against
+            // == * it raises an error
+            result = az_groups.petitions_create(0, accounts.bob, PetitionAction::Remove);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // == when target is an admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(5);
+            // === when petitions total is u32 max
+            az_groups.petitions_total = u32::MAX;
+            // === * it raises an error
+            result = az_groups.petitions_create(0, accounts.charlie, PetitionAction::Remove);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Petition limit reached".to_string()
+                ))
+            );
+            // === when petitions total is below u32 max
+            az_groups.petitions_total = 0;
+            // === * it creates the petition signed by the caller
+            result = az_groups.petitions_create(0, accounts.charlie, PetitionAction::Remove);
+            let petition: Petition = result.unwrap();
+            assert_eq!(
+                petition,
+                Petition {
+                    id: 0,
+                    group_id: 0,
+                    target: accounts.charlie,
+                    action: PetitionAction::Remove,
+                    threshold: 50,
+                    ends_at: 105,
+                    signatures: 1,
+                    status: PetitionStatus::Active,
+                }
+            );
+            assert_eq!(az_groups.petitions_total, 1);
+            assert!(az_groups
+                .petition_signatures
+                .get((0, accounts.django))
+                .is_some());
+            // === * it doesn't carry out the action until the threshold is reached
+            assert_eq!(
+                az_groups
+                    .group_users_show(0, accounts.charlie)
+                    .unwrap()
+                    .role,
+                Role::Admin
+            );
+        }
+
+        #[ink::test]
+        fn test_poll_votes_create() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            for user in [accounts.charlie, accounts.django] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(user);
+                az_groups.group_users_create(0).unwrap();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Member)
+                .unwrap();
+            az_groups.group_weight_limits_update(0, 5).unwrap();
+            az_groups
+                .group_user_weights_update(0, accounts.charlie, 3)
+                .unwrap();
+            // when poll does not exist
+            // * it raises an error
+            let mut result = az_groups.poll_votes_create(0, 0);
+            assert_eq!(result, Err(AZGroupsError::NotFound("Poll".to_string())));
+            // when poll exists
+            az_groups
+                .polls_create(0, Hash::default(), 2, 10, 20)
+                .unwrap();
+            // = when caller is not a member
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // = * it raises an error
+            result = az_groups.poll_votes_create(0, 0);
+            assert_eq!(result, Err(AZGroupsError::NotAMember));
+            // = when caller is a member
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // == when poll hasn't opened
+            // == * it raises an error
+            result = az_groups.poll_votes_create(0, 0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Poll hasn't opened".to_string()
+                ))
+            );
+            // == when poll is open
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(10);
+            // === when option is invalid
+            // === * it raises an error
+            result = az_groups.poll_votes_create(0, 2);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Option is invalid".to_string()
+                ))
+            );
+            // === when option is valid
+            // === * it adds one to the option's tally, whatever the caller's weight
+            result = az_groups.poll_votes_create(0, 1);
+            assert_eq!(result.unwrap().tallies, vec![0, 1]);
+            assert_eq!(az_groups.poll_votes.get((0, accounts.charlie)), Some(1));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            result = az_groups.poll_votes_create(0, 0);
+            assert_eq!(result.unwrap().tallies, vec![1, 1]);
+            // === when caller has already voted
+            // === * it raises an error
+            result = az_groups.poll_votes_create(0, 1);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Vote has already been taken".to_string()
+                ))
+            );
+            // == when poll has closed
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            az_groups.group_users.insert(
+                (0, accounts.django),
+                &GroupUser {
+                    role: Role::Member,
+                    weight: 1,
+                },
+            );
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(20);
+            // == * it raises an error
+            result = az_groups.poll_votes_create(0, 0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Poll has closed".to_string()
+                ))
+            );
+            // == * it keeps the final tallies
+            assert_eq!(az_groups.polls_show(0).unwrap().tallies, vec![1, 1]);
+        }
+
+        #[ink::test]
+        fn test_polls_create() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            // when caller is a member
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Member)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // * it raises an error
+            let mut result = az_groups.polls_create(0, Hash::default(), 2, 0, 10);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is an admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Admin)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(5);
+            // = when there are less than 2 or more than 10 options
+            // = * it raises an error
+            for options in [1, 11] {
+                result = az_groups.polls_create(0, Hash::default(), options, 5, 10);
+                assert_eq!(
+                    result,
+                    Err(AZGroupsError::UnprocessableEntity(
+                        "Options must be between 2 and 10".to_string()
+                    ))
+                );
+            }
+            // = when closes at isn't after opens at or is in the past
+            // = * it raises an error
+            for (opens_at, closes_at) in [(10, 10), (0, 5)] {
+                result = az_groups.polls_create(0, Hash::default(), 2, opens_at, closes_at);
+                assert_eq!(
+                    result,
+                    Err(AZGroupsError::UnprocessableEntity(
+                        "Closes at must be after opens at and in the future".to_string()
+                    ))
+                );
+            }
+            // = when polls total is u32 max
+            az_groups.polls_total = u32::MAX;
+            // = * it raises an error
+            result = az_groups.polls_create(0, Hash::default(), 2, 5, 10);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Poll limit reached".to_string()
+                ))
+            );
+            // = when poll is valid
+            az_groups.polls_total = 0;
+            // = * it creates the poll with an empty tally per option
+            result = az_groups.polls_create(0, Hash::default(), 3, 5, 10);
+            assert_eq!(
+                result,
+                Ok(Poll {
+                    id: 0,
+                    group_id: 0,
+                    creator: accounts.charlie,
+                    question_hash: Hash::default(),
+                    opens_at: 5,
+                    closes_at: 10,
+                    tallies: vec![0, 0, 0],
+                })
+            );
+            assert_eq!(az_groups.polls_total, 1);
+        }
+
+        #[ink::test]
+        fn test_proposal_votes_create() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            az_groups.group_governances_update(0, 2, 60, 1_000).unwrap();
+            for user in [accounts.charlie, accounts.django, accounts.eve] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(user);
+                az_groups.group_users_create(0).unwrap();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Member)
+                .unwrap();
+            az_groups
+                .group_users_update(0, accounts.django, Role::Member)
+                .unwrap();
+            let action: ProposalAction = ProposalAction::GroupUserUpdate {
+                user: accounts.eve,
+                role: Role::Member,
+            };
+            // when proposal does not exist
+            // * it raises an error
+            let mut result = az_groups.proposal_votes_create(0, true);
+            assert_eq!(result, Err(AZGroupsError::NotFound("Proposal".to_string())));
+            // when proposal exists
+            az_groups
+                .proposals_create(0, Hash::default(), action.clone())
+                .unwrap();
+            // = when caller is not a member
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            // = * it raises an error
+            result = az_groups.proposal_votes_create(0, true);
+            assert_eq!(result, Err(AZGroupsError::NotAMember));
+            // = when caller is a member
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // == when the quorum hasn't been reached
+            // == * it records the vote
+            let proposal: Proposal = az_groups.proposal_votes_create(0, true).unwrap();
+            assert_eq!(proposal.votes_for, 1);
+            assert_eq!(proposal.status, ProposalStatus::Active);
+            // == when caller has already voted
+            // == * it raises an error
+            result = az_groups.proposal_votes_create(0, false);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Vote has already been taken".to_string()
+                ))
+            );
+            // == when the quorum is reached but the threshold isn't
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // == * it keeps the proposal active
+            result = az_groups.proposal_votes_create(0, false);
+            assert_eq!(result.unwrap().status, ProposalStatus::Active);
+            // == when the quorum and threshold are reached
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // == * it executes the action
+            result = az_groups.proposal_votes_create(0, true);
+            assert_eq!(result.unwrap().status, ProposalStatus::Executed);
+            assert_eq!(
+                az_groups.group_users_show(0, accounts.eve).unwrap().role,
+                Role::Member
+            );
+            // = when proposal is not active
+            // = * it raises an error
+            result = az_groups.proposal_votes_create(0, true);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Proposal is not active".to_string()
+                ))
+            );
+            // = when voting period has ended
+            az_groups
+                .proposals_create(0, Hash::default(), action)
+                .unwrap();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+            // = * it raises an error
+            result = az_groups.proposal_votes_create(1, true);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Voting period has ended".to_string()
+                ))
+            );
+            // = when the action can no longer be carried out
+            az_groups
+                .proposals_create(
+                    0,
+                    Hash::default(),
+                    ProposalAction::GroupUserDestroy {
+                        user: accounts.charlie,
+                    },
+                )
+                .unwrap();
+            az_groups.proposal_votes_create(2, true).unwrap();
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::SuperAdmin)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // = * it marks the proposal as failed
+            result = az_groups.proposal_votes_create(2, true);
+            assert_eq!(result.unwrap().status, ProposalStatus::Failed);
+            assert!(az_groups.group_users_show(0, accounts.charlie).is_ok());
+            // = when the action is a treasury spend
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            az_groups.group_treasuries_deposit(0).unwrap();
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(
+                ink::env::test::callee::<ink::env::DefaultEnvironment>(),
+                100,
+            );
+            let eve_balance: Balance =
+                ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.eve)
+                    .unwrap();
+            az_groups
+                .proposals_create(
+                    0,
+                    Hash::default(),
+                    ProposalAction::TreasurySpend {
+                        to: accounts.eve,
+                        amount: 40,
+                    },
+                )
+                .unwrap();
+            az_groups.proposal_votes_create(3, true).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = * it transfers from the treasury
+            result = az_groups.proposal_votes_create(3, true);
+            assert_eq!(result.unwrap().status, ProposalStatus::Executed);
+            assert_eq!(az_groups.group_treasuries_show(0), 60);
+            assert_eq!(
+                ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.eve),
+                Ok(eve_balance + 40)
+            );
+            // = when voters have different weights
+            az_groups.group_weight_limits_update(0, 5).unwrap();
+            az_groups
+                .group_user_weights_update(0, accounts.django, 3)
+                .unwrap();
+            az_groups
+                .proposals_create(
+                    0,
+                    Hash::default(),
+                    ProposalAction::GroupUserUpdate {
+                        user: accounts.eve,
+                        role: Role::Member,
+                    },
+                )
+                .unwrap();
+            az_groups.proposal_votes_create(4, false).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // = * it counts each vote as the voter's weight
+            let proposal: Proposal = az_groups.proposal_votes_create(4, true).unwrap();
+            assert_eq!(proposal.votes_for, 3);
+            assert_eq!(proposal.votes_against, 1);
+            assert_eq!(proposal.status, ProposalStatus::Executed);
+        }
+
+        #[ink::test]
+        fn test_proposals_create() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            let action: ProposalAction = ProposalAction::GroupsUpdate {
+                name: "The Last Wave".to_string(),
+                enabled: true,
+            };
+            // when caller is not a member
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            // * it raises an error
+            let mut result = az_groups.proposals_create(0, Hash::default(), action.clone());
+            assert_eq!(result, Err(AZGroupsError::NotAMember));
+            // when caller is a member
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when group governance has not been set
+            // = * it raises an error
+            result = az_groups.proposals_create(0, Hash::default(), action.clone());
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupGovernance".to_string()))
+            );
+            // = when group governance has been set
+            az_groups.group_governances_update(0, 2, 50, 1_000).unwrap();
+            // == when action targets a super admin
+            // == * it raises an error
+            result = az_groups.proposals_create(
+                0,
+                Hash::default(),
+                ProposalAction::GroupUserDestroy { user: accounts.bob },
+            );
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // == when action makes a super admin
+            // == * it raises an error
+            result = az_groups.proposals_create(
+                0,
+                Hash::default(),
+                ProposalAction::GroupUserUpdate {
+                    user: accounts.charlie,
+                    role: Role::SuperAdmin,
+                },
+            );
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // == when action makes an admin
+            // == * it raises an error
+            result = az_groups.proposals_create(
+                0,
+                Hash::default(),
+                ProposalAction::GroupUserUpdate {
+                    user: accounts.charlie,
+                    role: Role::Admin,
+                },
+            );
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // == when action spends more than the treasury
+            // == * it raises an error
+            result = az_groups.proposals_create(
+                0,
+                Hash::default(),
+                ProposalAction::TreasurySpend {
+                    to: accounts.charlie,
+                    amount: 1,
+                },
+            );
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Insufficient treasury balance".to_string()
+                ))
+            );
+            // == when action is valid
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(5);
+            // == * it creates the proposal
+            result = az_groups.proposals_create(0, Hash::default(), action.clone());
+            let proposal: Proposal = result.unwrap();
+            assert_eq!(proposal.id, 0);
+            assert_eq!(proposal.proposer, accounts.bob);
+            assert_eq!(proposal.action, action);
+            assert_eq!(proposal.ends_at, 1_005);
+            assert_eq!(proposal.quorum, 2);
+            assert_eq!(proposal.threshold, 50);
+            assert_eq!(proposal.status, ProposalStatus::Active);
+            assert_eq!(az_groups.proposals_show(0), Ok(proposal));
+            // == * it increases the proposals total
+            assert_eq!(az_groups.proposals_total, 1);
+        }
+
+        #[ink::test]
+        fn test_reserved_names_create() {
+            let (accounts, mut az_groups) = init();
+            // when caller is not the owner
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // * it raises an error
+            let mut result = az_groups.reserved_names_create("Admin".to_string());
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is the owner
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when name is blank
+            // = * it raises an error
+            result = az_groups.reserved_names_create(" ".to_string());
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Name can't be blank".to_string()
+                ))
+            );
+            // = when name is present
+            // = * it reserves the formatted name
+            result = az_groups.reserved_names_create(" Admin ".to_string());
+            assert_eq!(result, Ok("Admin".to_string()));
+            // = * it can be looked up by any name with the same key
+            assert_eq!(
+                az_groups.reserved_names_show("\u{0410}DMIN".to_string()),
+                Ok("Admin".to_string())
+            );
+            // = when name has already been reserved
+            // = * it raises an error
+            result = az_groups.reserved_names_create("admin".to_string());
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Reserved name has already been taken".to_string()
+                ))
+            );
+        }
+
+        #[ink::test]
+        fn test_reserved_names_destroy() {
+            let (accounts, mut az_groups) = init();
+            // when reserved name does not exist
+            // * it raises an error
+            let mut result = az_groups.reserved_names_destroy("Admin".to_string());
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("ReservedName".to_string()))
+            );
+            // when reserved name exists
+            az_groups
+                .reserved_names_create("Admin".to_string())
+                .unwrap();
+            // = when caller is not the owner
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // = * it raises an error
+            result = az_groups.reserved_names_destroy("Admin".to_string());
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // = when caller is the owner
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = * it removes the reserved name
+            result = az_groups.reserved_names_destroy("admin".to_string());
+            assert_eq!(result, Ok(()));
+            assert!(az_groups.reserved_names_show("Admin".to_string()).is_err());
+            assert!(az_groups.groups_create("Admin".to_string()).is_ok());
+        }
+
+        #[ink::test]
+        fn test_super_admin_claim_supports_create() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            az_groups.group_recoveries_update(0, 1_000, 100).unwrap();
+            for user in [accounts.charlie, accounts.django, accounts.eve] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(user);
+                az_groups.group_users_create(0).unwrap();
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+                az_groups.group_users_update(0, user, Role::Admin).unwrap();
+            }
+            az_groups
+                .group_users_update(0, accounts.eve, Role::Member)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // when claim does not exist
+            // * it raises an error
+            let mut result = az_groups.super_admin_claim_supports_create(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("SuperAdminClaim".to_string()))
+            );
+            // when claim exists
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.super_admin_claims_create(0).unwrap();
+            // = when caller is not an admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            // = * it raises an error
+            result = az_groups.super_admin_claim_supports_create(0);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // = when caller is an admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // == * it adds the caller as a supporter
+            result = az_groups.super_admin_claim_supports_create(0);
+            assert_eq!(
+                result.unwrap().supporters,
+                vec![accounts.charlie, accounts.django]
+            );
+            // == when caller already supports the claim
+            // == * it raises an error
+            result = az_groups.super_admin_claim_supports_create(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Support has already been taken".to_string()
+                ))
+            );
+        }
+
+        #[ink::test]
+        fn test_super_admin_claims_create() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            for user in [accounts.charlie, accounts.django] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(user);
+                az_groups.group_users_create(0).unwrap();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Admin)
+                .unwrap();
+            // when caller is not an admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // * it raises an error
+            let mut result = az_groups.super_admin_claims_create(0);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is an admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // = when group recovery has not been set
+            // = * it raises an error
+            result = az_groups.super_admin_claims_create(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupRecovery".to_string()))
+            );
+            // = when group recovery has been set
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups.group_recoveries_update(0, 1_000, 100).unwrap();
+            az_groups
+                .group_users_update(0, accounts.django, Role::Admin)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // == when a super admin has been active within the inactivity period
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(999);
+            // == * it raises an error
+            result = az_groups.super_admin_claims_create(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Super admin is still active".to_string()
+                ))
+            );
+            // == when no super admin has been active within the inactivity period
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+            // == * it creates the claim
+            result = az_groups.super_admin_claims_create(0);
+            let super_admin_claim: SuperAdminClaim = SuperAdminClaim {
+                group_id: 0,
+                claimant: accounts.charlie,
+                supporters: vec![accounts.charlie],
+                challenge_ends_at: 1_100,
+            };
+            assert_eq!(result, Ok(super_admin_claim.clone()));
+            assert_eq!(az_groups.super_admin_claims_show(0), Ok(super_admin_claim));
+            // == when a claim already exists
+            // === when the claim is still open
+            // === * it raises an error
+            result = az_groups.super_admin_claims_create(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Super admin claim has already been taken".to_string()
+                ))
+            );
+            // === when the claimant is no longer an admin
+            az_groups.group_users_destroy(0, accounts.charlie).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // === * it replaces the claim
+            result = az_groups.super_admin_claims_create(0);
+            assert_eq!(result.unwrap().claimant, accounts.django);
+            // === when the challenge period has ended
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_100);
+            // === * it replaces the claim
+            result = az_groups.super_admin_claims_create(0);
+            assert_eq!(result.unwrap().challenge_ends_at, 1_200);
+        }
+
+        #[ink::test]
+        fn test_super_admin_claims_destroy() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            az_groups.group_recoveries_update(0, 1_000, 100).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Admin)
+                .unwrap();
+            // when claim does not exist
+            // * it raises an error
+            let mut result = az_groups.super_admin_claims_destroy(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("SuperAdminClaim".to_string()))
+            );
+            // when claim exists
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.super_admin_claims_create(0).unwrap();
+            // = when caller is not a super admin
+            // == when caller is not an admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // == * it raises an error
+            result = az_groups.super_admin_claims_destroy(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupUser".to_string()))
+            );
+            // == when caller is an admin
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // === when the claim is still open
+            // === * it raises an error
+            result = az_groups.super_admin_claims_destroy(0);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // === when the challenge period has ended
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_100);
+            // === * it clears the claim
+            result = az_groups.super_admin_claims_destroy(0);
+            assert_eq!(result, Ok(()));
+            assert!(az_groups.super_admin_claims_show(0).is_err());
+            // === when the claimant is no longer an admin
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(2_100);
+            az_groups.super_admin_claims_create(0).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            az_groups.group_users_create(0).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.django, Role::Admin)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_destroy(0, accounts.charlie).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            // === * it clears the claim
+            result = az_groups.super_admin_claims_destroy(0);
+            assert_eq!(result, Ok(()));
+            assert!(az_groups.super_admin_claims_show(0).is_err());
+            // = when caller is a super admin
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(3_200);
+            az_groups.super_admin_claims_create(0).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = * it cancels the claim
+            result = az_groups.super_admin_claims_destroy(0);
+            assert_eq!(result, Ok(()));
+            assert!(az_groups.super_admin_claims_show(0).is_err());
+            // = * it records the super admin's activity
+            assert_eq!(az_groups.group_super_admin_activities_show(0), 3_200);
+        }
+
+        #[ink::test]
+        fn test_super_admin_claims_execute() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            az_groups.group_recoveries_update(0, 1_000, 100).unwrap();
+            for user in [accounts.charlie, accounts.django, accounts.eve, accounts.frank] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(user);
+                az_groups.group_users_create(0).unwrap();
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+                az_groups.group_users_update(0, user, Role::Admin).unwrap();
+            }
+            // when claim does not exist
+            // * it raises an error
+            let mut result = az_groups.super_admin_claims_execute(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("SuperAdminClaim".to_string()))
+            );
+            // when claim exists
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.super_admin_claims_create(0).unwrap();
+            // = when challenge period has not ended
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_099);
+            // = * it raises an error
+            result = az_groups.super_admin_claims_execute(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Challenge period has not ended".to_string()
+                ))
+            );
+            // = when challenge period has ended
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_100);
+            // == when claim isn't supported by a majority of admins
+            // == * it raises an error
+            result = az_groups.super_admin_claims_execute(0);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Claim needs the support of a majority of admins".to_string()
+                ))
+            );
+            // == when claim is supported by a majority of admins
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            az_groups.super_admin_claim_supports_create(0).unwrap();
+            // === when an elected admin's term has ended
+            // === * they aren't counted as an admin
+            az_groups.group_user_terms_insert(0, accounts.frank, 1_100);
+            // == * it makes the claimant a super admin (anyone can execute)
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.frank);
+            result = az_groups.super_admin_claims_execute(0);
+            assert_eq!(result.unwrap().role, Role::SuperAdmin);
+            assert_eq!(
+                az_groups
+                    .group_users_show(0, accounts.charlie)
+                    .unwrap()
+                    .role,
+                Role::SuperAdmin
+            );
+            // == * it removes the claim
+            assert!(az_groups.super_admin_claims_show(0).is_err());
+            // == * it records the new super admin's activity
+            assert_eq!(az_groups.group_super_admin_activities_show(0), 1_100);
+        }
+
+        #[ink::test]
+        fn test_tag_groups_index() {
+            let (_accounts, mut az_groups) = init();
+            az_groups.tags_create("dao".to_string()).unwrap();
+            for name in ["A", "B", "C"] {
+                let group: Group = az_groups.groups_create(name.to_string()).unwrap();
+                az_groups
+                    .group_tags_update(group.id, vec!["dao".to_string()])
+                    .unwrap();
             }
-            // key will be name lowercased
-            // check if group with key already exists
-            let key: String = formatted_name.to_lowercase();
-            self.validate_group_name_uniqueness(key.clone())?;
-
-            let user: AccountId = Self::env().caller();
-            // Create group
-            let group: Group = Group {
-                id: self.groups_total,
-                name: formatted_name.clone(),
-                enabled: true,
-            };
-            self.groups.insert(group.id, &group);
-
-            // Map group name to id
-            self.group_id_by_name.insert(key, &group.id);
-
-            // Create and set group user
-            let group_user: GroupUser = GroupUser {
-                role: Role::SuperAdmin,
-            };
-            self.group_users.insert((group.id, user), &group_user);
-
-            // Increase groups_total
-            self.groups_total += 1;
-
-            // emit event
-            self.env().emit_event(Create {
-                id: group.id,
-                name: formatted_name,
-            });
-            self.env().emit_event(GroupUserCreate {
-                group_id: group.id,
-                user,
-                role: group_user.role,
-            });
-
-            Ok(group)
+            // when tag has no groups
+            // * it returns an empty list
+            assert!(az_groups
+                .tag_groups_index("auditor".to_string(), 0, 10)
+                .is_empty());
+            // when tag has groups
+            // * it returns the page of group ids
+            assert_eq!(
+                az_groups.tag_groups_index("DAO".to_string(), 0, 10),
+                vec![0, 1, 2]
+            );
+            assert_eq!(az_groups.tag_groups_index("dao".to_string(), 1, 1), vec![1]);
+            assert!(az_groups
+                .tag_groups_index("dao".to_string(), 3, 10)
+                .is_empty());
+            assert_eq!(
+                az_groups
+                    .tag_groups_index("dao".to_string(), 0, u32::MAX)
+                    .len(),
+                3
+            );
+            // when a group is disabled
+            // * it removes the group
+            az_groups.groups_update(0, "A".to_string(), false).unwrap();
+            assert_eq!(
+                az_groups.tag_groups_index("dao".to_string(), 0, 10),
+                vec![2, 1]
+            );
+            // * it keeps the group's tags
+            assert_eq!(az_groups.group_tags_index(0), vec!["dao".to_string()]);
+            // when a group is enabled
+            // * it adds the group back
+            az_groups.groups_update(0, "A".to_string(), true).unwrap();
+            assert_eq!(
+                az_groups.tag_groups_index("dao".to_string(), 0, 10),
+                vec![2, 1, 0]
+            );
         }
 
-        #[ink(message)]
-        pub fn groups_find_by_name(&self, name: String) -> Result<Group, AZGroupsError> {
-            if let Some(group_id) = self.group_id_by_name.get(name.to_lowercase()) {
-                self.groups_show(group_id)
-            } else {
-                Err(AZGroupsError::NotFound("Group".to_string()))
+        #[ink::test]
+        fn test_tags_create() {
+            let (accounts, mut az_groups) = init();
+            // when caller is not the owner
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // * it raises an error
+            let mut result = az_groups.tags_create("dao".to_string());
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is the owner
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when tag is invalid
+            // = * it raises an error
+            for (tag, message) in [
+                ("a".repeat(33), "Tag is too long"),
+                (" ".to_string(), "Tag is invalid"),
+                ("smart contracts".to_string(), "Tag is invalid"),
+                ("d\u{00E4}o".to_string(), "Tag is invalid"),
+            ] {
+                result = az_groups.tags_create(tag);
+                assert_eq!(
+                    result,
+                    Err(AZGroupsError::UnprocessableEntity(message.to_string()))
+                );
             }
+            // = when tag is valid
+            // = * it adds the formatted tag to the vocabulary
+            result = az_groups.tags_create(" Smart-Contracts ".to_string());
+            assert_eq!(result.unwrap(), "smart-contracts");
+            assert_eq!(
+                az_groups.tags_show("smart-contracts".to_string()).unwrap(),
+                "smart-contracts"
+            );
+            // = when tag already exists
+            // = * it raises an error
+            result = az_groups.tags_create("smart-contracts".to_string());
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Tag has already been taken".to_string()
+                ))
+            );
         }
 
-        #[ink(message)]
-        pub fn groups_show(&self, id: u32) -> Result<Group, AZGroupsError> {
-            self.groups
-                .get(id)
-                .ok_or(AZGroupsError::NotFound("Group".to_string()))
+        #[ink::test]
+        fn test_tags_destroy() {
+            let (accounts, mut az_groups) = init();
+            // when caller is not the owner
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // * it raises an error
+            let mut result = az_groups.tags_destroy("dao".to_string());
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is the owner
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when tag does not exist
+            // = * it raises an error
+            result = az_groups.tags_destroy("dao".to_string());
+            assert_eq!(result, Err(AZGroupsError::NotFound("Tag".to_string())));
+            // = when tag exists
+            az_groups.tags_create("dao".to_string()).unwrap();
+            // = * it removes the tag from the vocabulary
+            az_groups.tags_destroy("DAO".to_string()).unwrap();
+            assert_eq!(
+                az_groups.tags_show("dao".to_string()),
+                Err(AZGroupsError::NotFound("Tag".to_string()))
+            );
         }
 
-        #[ink(message)]
-        pub fn groups_update(
-            &mut self,
-            id: u32,
-            name: String,
-            enabled: bool,
-        ) -> Result<Group, AZGroupsError> {
-            let mut group: Group = self.groups_show(id)?;
-            let caller: AccountId = Self::env().caller();
-            let caller_group_user: GroupUser = self.group_users_show(id, caller)?;
-            if caller_group_user.role != Role::SuperAdmin {
-                return Err(AZGroupsError::Unauthorised);
-            }
+        #[ink::test]
+        fn test_validate_membership() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            // when group with id does not exist
+            // * it raises an error
+            let mut result = az_groups.validate_membership(0, accounts.bob);
+            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
+            // when group with id exists
+            let mut group: Group = az_groups.groups_create(group_name).unwrap();
+            // = when group is enabled
+            // == when GroupUser doesn't exist
+            result = az_groups.validate_membership(0, accounts.alice);
+            // = * it raises an error
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupUser".to_string()))
+            );
+            // == when GroupUser exists
+            // === when GroupUser is a member, admin or super admin
+            // === * it returns the role number
+            az_groups.group_users.insert(
+                (0, accounts.bob),
+                &GroupUser {
+                    role: Role::Member,
+                    weight: 1,
+                },
+            );
+            result = az_groups.validate_membership(0, accounts.bob);
+            assert_eq!(result.unwrap(), Role::Member);
+            // === when GroupUser is banned or applicant
+            // === * it raises an error
+            az_groups.group_users.insert(
+                (0, accounts.bob),
+                &GroupUser {
+                    role: Role::Applicant,
+                    weight: 1,
+                },
+            );
+            result = az_groups.validate_membership(0, accounts.bob);
+            assert_eq!(result, Err(AZGroupsError::NotAMember));
+            // = when group is disabled
+            group.enabled = false;
+            az_groups.groups.insert(0, &group);
+            // = * it raises an error
+            result = az_groups.validate_membership(0, accounts.bob);
+            assert_eq!(result, Err(AZGroupsError::GroupDisabled));
+        }
 
-            if group.name != name {
-                let name_formatted = AZGroups::format_group_name(name);
-                AZGroups::validate_group_name_presence(name_formatted.clone())?;
+        #[ink::test]
+        fn test_validate_verified_membership() {
+            let (accounts, mut az_groups) = init();
+            // when group does not exist
+            // * it raises an error
+            let mut result = az_groups.validate_verified_membership(0, accounts.bob);
+            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
+            // when group exists
+            az_groups
+                .groups_create(MOCK_GROUP_NAME.to_string())
+                .unwrap();
+            // = when group is not verified
+            // = * it raises an error
+            result = az_groups.validate_verified_membership(0, accounts.bob);
+            assert_eq!(result, Err(AZGroupsError::GroupUnverified));
+            // = when group is verified
+            az_groups.verifiers_create(accounts.django).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            az_groups
+                .group_verifications_create(0, [1; 32].into(), 10)
+                .unwrap();
+            // = * it validates the membership
+            result = az_groups.validate_verified_membership(0, accounts.bob);
+            assert_eq!(result, Ok(Role::SuperAdmin));
+            result = az_groups.validate_verified_membership(0, accounts.charlie);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupUser".to_string()))
+            );
+            // = when verification has expired
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(10);
+            // = * it raises an error
+            result = az_groups.validate_verified_membership(0, accounts.bob);
+            assert_eq!(result, Err(AZGroupsError::GroupUnverified));
+        }
 
-                let new_key: String = name_formatted.to_lowercase();
-                let old_key: String = group.name.to_lowercase();
-                if new_key != old_key {
-                    self.validate_group_name_uniqueness(new_key.clone())?
-                }
+        #[ink::test]
+        fn test_verifiers_create() {
+            let (accounts, mut az_groups) = init();
+            // when caller is not the owner
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // * it raises an error
+            let mut result = az_groups.verifiers_create(accounts.django);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is the owner
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when verifier does not exist
+            // = * it adds the verifier
+            result = az_groups.verifiers_create(accounts.django);
+            assert_eq!(result.unwrap(), accounts.django);
+            assert_eq!(
+                az_groups.verifiers_show(accounts.django).unwrap(),
+                accounts.django
+            );
+            // = when verifier already exists
+            // = * it raises an error
+            result = az_groups.verifiers_create(accounts.django);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Verifier has already been taken".to_string()
+                ))
+            );
+        }
 
-                // remove old mapping
-                self.group_id_by_name.remove(old_key);
-                group.name = name_formatted;
-                self.group_id_by_name.insert(new_key, &id);
-            }
-            group.enabled = enabled;
-            self.groups.insert(id, &group);
+        #[ink::test]
+        fn test_verifiers_destroy() {
+            let (accounts, mut az_groups) = init();
+            // when caller is not the owner
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // * it raises an error
+            let mut result = az_groups.verifiers_destroy(accounts.django);
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is the owner
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = when verifier does not exist
+            // = * it raises an error
+            result = az_groups.verifiers_destroy(accounts.django);
+            assert_eq!(result, Err(AZGroupsError::NotFound("Verifier".to_string())));
+            // = when verifier exists
+            az_groups.verifiers_create(accounts.django).unwrap();
+            az_groups
+                .groups_create(MOCK_GROUP_NAME.to_string())
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            az_groups
+                .group_verifications_create(0, Hash::default(), 1_000)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // = * it removes the verifier
+            az_groups.verifiers_destroy(accounts.django).unwrap();
+            assert_eq!(
+                az_groups.verifiers_show(accounts.django),
+                Err(AZGroupsError::NotFound("Verifier".to_string()))
+            );
+            // = * it hides the verifier's verifications
+            assert!(az_groups.groups_show(0).unwrap().verification.is_none());
+            // = * it keeps the verifications when the group is updated
+            az_groups
+                .groups_update(0, "The Last Wave".to_string(), true)
+                .unwrap();
+            az_groups.verifiers_create(accounts.django).unwrap();
+            assert_eq!(
+                az_groups.groups_show(0).unwrap().verification,
+                Some(GroupVerification {
+                    verifier: accounts.django,
+                    evidence_hash: Hash::default(),
+                    expires_at: 1_000,
+                })
+            );
+        }
 
-            // emit event
-            self.env().emit_event(Update {
-                id,
-                name: group.name.clone(),
-                enabled: group.enabled,
-            });
+        #[ink::test]
+        fn test_vouch() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            for user in [accounts.charlie, accounts.django, accounts.eve] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(user);
+                az_groups.group_users_create(0).unwrap();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            az_groups
+                .group_users_update(0, accounts.charlie, Role::Member)
+                .unwrap();
+            // when vouching is not enabled
+            // * it raises an error
+            let mut result = az_groups.vouch(0, accounts.django);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Vouching is not enabled".to_string()
+                ))
+            );
+            // when vouching is enabled
+            az_groups.group_vouch_thresholds_update(0, 2).unwrap();
+            // = when caller is not a member
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            // = * it raises an error
+            result = az_groups.vouch(0, accounts.django);
+            assert_eq!(result, Err(AZGroupsError::NotAMember));
+            // = when caller is a member
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // == when user is not an applicant
+            // == * it raises an error
+            result = az_groups.vouch(0, accounts.charlie);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "User is not an applicant".to_string()
+                ))
+            );
+            // == when user is an applicant
+            // === when threshold hasn't been reached
+            // === * it records the vouch
+            result = az_groups.vouch(0, accounts.django);
+            assert_eq!(result, Ok(vec![accounts.bob]));
+            assert_eq!(
+                az_groups.group_users_show(0, accounts.django).unwrap().role,
+                Role::Applicant
+            );
+            // === when caller has already vouched
+            // === * it raises an error
+            result = az_groups.vouch(0, accounts.django);
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Vouch has already been taken".to_string()
+                ))
+            );
+            // === when threshold has been reached
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            // === * it makes the applicant a member
+            az_groups.vouch(0, accounts.django).unwrap();
+            assert_eq!(
+                az_groups.group_users_show(0, accounts.django).unwrap().role,
+                Role::Member
+            );
+            // === * it removes the vouches
+            assert!(az_groups.vouches_index(0, accounts.django).is_empty());
+        }
 
-            Ok(group)
+        #[ink::test]
+        fn test_vouches_destroy() {
+            let (accounts, mut az_groups) = init();
+            let group_name: String = MOCK_GROUP_NAME.to_string();
+            az_groups.groups_create(group_name).unwrap();
+            az_groups.group_vouch_thresholds_update(0, 2).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            az_groups.group_users_create(0).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // when caller has not vouched
+            // * it raises an error
+            let mut result = az_groups.vouches_destroy(0, accounts.charlie);
+            assert_eq!(result, Err(AZGroupsError::NotFound("Vouch".to_string())));
+            // when caller has vouched
+            az_groups.vouch(0, accounts.charlie).unwrap();
+            // * it withdraws the vouch
+            result = az_groups.vouches_destroy(0, accounts.charlie);
+            assert_eq!(result, Ok(vec![]));
+            assert!(az_groups.vouches_index(0, accounts.charlie).is_empty());
         }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use allowlist_join_condition::AllowlistJoinConditionRef;
+        use ink::prelude::vec;
+        use ink_e2e::{build_message, subxt::blocks::ExtrinsicEvents};
+        use membership_consumer::MembershipConsumerRef;
+        use mock_domain_registry::MockDomainRegistryRef;
 
-        // Convenience method so that other contract can get this info without having to call two functions
-        // 1. Check that the group is enabled
-        // 2. Check that user has a role with the group greater than or equal to two
-        #[ink(message)]
-        pub fn validate_membership(
-            &self,
-            group_id: u32,
-            user: AccountId,
-        ) -> Result<Role, AZGroupsError> {
-            let group: Group = self.groups_show(group_id)?;
-            if !group.enabled {
-                return Err(AZGroupsError::GroupDisabled);
-            }
-            let group_user: GroupUser = self.group_users_show(group_id, user)?;
-            if group_user.role.to_int() < 2 {
-                return Err(AZGroupsError::NotAMember);
-            }
+        // Each area of messages has an end to end test. Checks that need time to pass on chain
+        // e.g. closing an election or executing a pending promotion are covered by the unit tests.
 
-            Ok(group_user.role)
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+        type Event = <AZGroups as ink::reflect::ContractEventBase>::Type;
+
+        // === HELPERS ===
+        // Decodes the events emitted by contracts during the extrinsic.
+        fn contract_events<C: ink_e2e::subxt::Config>(events: &ExtrinsicEvents<C>) -> Vec<Event> {
+            events
+                .iter()
+                .map(|event| event.unwrap())
+                .filter(|event| {
+                    event.pallet_name() == "Contracts" && event.variant_name() == "ContractEmitted"
+                })
+                .map(|event| {
+                    let (_contract, data): (AccountId, Vec<u8>) =
+                        scale::Decode::decode(&mut event.field_bytes()).unwrap();
+                    <Event as scale::Decode>::decode(&mut &data[..]).unwrap()
+                })
+                .collect()
         }
 
-        fn format_group_name(name: String) -> String {
-            name.trim().to_string()
+        // The test contracts depend on their own copy of this crate,
+        // so their return values are decoded as this crate's types.
+        fn return_value<V: scale::Decode>(return_data: &[u8]) -> V {
+            <ink::MessageResult<V> as scale::Decode>::decode(&mut &return_data[..])
+                .unwrap()
+                .unwrap()
         }
 
-        fn validate_group_name_presence(name: String) -> Result<(), AZGroupsError> {
-            if name.is_empty() {
-                return Err(AZGroupsError::UnprocessableEntity(
-                    "Name can't be blank".to_string(),
-                ));
-            };
+        // === TESTS ===
+        #[ink_e2e::test]
+        async fn e2e_groups(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let az_groups_id = client
+                .instantiate("az_groups", &ink_e2e::alice(), AZGroupsRef::new(), 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let alice: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+
+            // groups_create
+            let groups_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_create(" The Next Wave ".to_string()));
+            let result = client
+                .call(&ink_e2e::alice(), groups_create, 0, None)
+                .await
+                .expect("groups_create failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert_eq!(
+                result.return_value(),
+                Ok(Group {
+                    id: 0,
+                    name: "The Next Wave".to_string(),
+                    enabled: true,
+                    domain: None,
+                    verification: None
+                })
+            );
+            assert!(matches!(
+                &events[..],
+                [
+                    Event::Create(Create { id: 0, name }),
+                    Event::GroupUserCreate(GroupUserCreate { group_id: 0, user, role: Role::SuperAdmin }),
+                    Event::GroupSlugUpdate(GroupSlugUpdate { group_id: 0, slug })
+                ] if name == "The Next Wave" && *user == alice && slug == "the-next-wave"
+            ));
+
+            // groups_show
+            let groups_show = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_show(0));
+            let group: Group = client
+                .call_dry_run(&ink_e2e::bob(), &groups_show, 0, None)
+                .await
+                .return_value()
+                .unwrap();
+            assert_eq!(group.name, "The Next Wave");
+
+            // groups_find_by_name
+            let groups_find_by_name = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_find_by_name("THE NEXT WAVE".to_string()));
+            let lookup: GroupNameLookup = client
+                .call_dry_run(&ink_e2e::bob(), &groups_find_by_name, 0, None)
+                .await
+                .return_value()
+                .unwrap();
+            assert_eq!(lookup.group.id, 0);
+            assert!(!lookup.redirected);
+
+            // groups_update
+            // when caller is not a super admin
+            let groups_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_update(0, "King Kong".to_string(), false));
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &groups_update, 0, None)
+                .await
+                .return_value();
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupUser".to_string()))
+            );
+            // when caller is a super admin
+            let result = client
+                .call(&ink_e2e::alice(), groups_update, 0, None)
+                .await
+                .expect("groups_update failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert!(matches!(
+                &events[..],
+                [Event::Update(Update { id: 0, name, enabled: false })] if name == "King Kong"
+            ));
 
             Ok(())
         }
 
-        fn validate_group_name_uniqueness(&self, key: String) -> Result<(), AZGroupsError> {
-            if self.group_id_by_name.get(key).is_some() {
-                return Err(AZGroupsError::UnprocessableEntity(
-                    "Group has already been taken".to_string(),
-                ));
-            }
+        #[ink_e2e::test]
+        async fn e2e_group_users(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let az_groups_id = client
+                .instantiate("az_groups", &ink_e2e::alice(), AZGroupsRef::new(), 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let bob: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let groups_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_create("The Next Wave".to_string()));
+            client
+                .call(&ink_e2e::alice(), groups_create, 0, None)
+                .await
+                .expect("groups_create failed");
+
+            // group_users_create
+            let group_users_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_users_create(0));
+            let result = client
+                .call(&ink_e2e::bob(), group_users_create, 0, None)
+                .await
+                .expect("group_users_create failed");
+            assert_eq!(result.return_value().unwrap().role, Role::Applicant);
+
+            // validate_membership
+            // when user is an applicant
+            let validate_membership = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.validate_membership(0, bob));
+            let result = client
+                .call_dry_run(&ink_e2e::charlie(), &validate_membership, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, Err(AZGroupsError::NotAMember));
+
+            // group_users_update
+            let group_users_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_users_update(0, bob, Role::Member));
+            let result = client
+                .call(&ink_e2e::alice(), group_users_update, 0, None)
+                .await
+                .expect("group_users_update failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert!(matches!(
+                &events[..],
+                [Event::GroupUserUpdate(GroupUserUpdate { group_id: 0, user, role: Role::Member })]
+                    if *user == bob
+            ));
+
+            // validate_membership
+            // when user is a member
+            let result = client
+                .call_dry_run(&ink_e2e::charlie(), &validate_membership, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, Ok(Role::Member));
+
+            // group_users_show
+            let group_users_show = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_users_show(0, bob));
+            let group_user: GroupUser = client
+                .call_dry_run(&ink_e2e::charlie(), &group_users_show, 0, None)
+                .await
+                .return_value()
+                .unwrap();
+            assert_eq!(group_user.role, Role::Member);
+
+            // group_users_destroy
+            let group_users_destroy = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_users_destroy(0, bob));
+            let result = client
+                .call(&ink_e2e::bob(), group_users_destroy, 0, None)
+                .await
+                .expect("group_users_destroy failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert!(matches!(
+                &events[..],
+                [Event::GroupUserDestroy(GroupUserDestroy { group_id: 0, user })] if *user == bob
+            ));
+            let result = client
+                .call_dry_run(&ink_e2e::charlie(), &group_users_show, 0, None)
+                .await
+                .return_value();
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupUser".to_string()))
+            );
 
             Ok(())
         }
-    }
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
-        use ink::env::{test::DefaultAccounts, DefaultEnvironment};
+        #[ink_e2e::test(
+            additional_contracts = "test_contracts/allowlist_join_condition/Cargo.toml"
+        )]
+        async fn e2e_group_join_conditions(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let az_groups_id = client
+                .instantiate("az_groups", &ink_e2e::alice(), AZGroupsRef::new(), 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let bob: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let allowlist_join_condition_id = client
+                .instantiate(
+                    "allowlist_join_condition",
+                    &ink_e2e::alice(),
+                    AllowlistJoinConditionRef::new(0, vec![bob]),
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let groups_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_create("The Next Wave".to_string()));
+            client
+                .call(&ink_e2e::alice(), groups_create, 0, None)
+                .await
+                .expect("groups_create failed");
 
-        // === CONSTANTS ===
-        const MOCK_GROUP_NAME: &str = "The Next Wave";
+            // group_join_conditions_update
+            let group_join_conditions_update =
+                build_message::<AZGroupsRef>(az_groups_id).call(|az_groups| {
+                    az_groups.group_join_conditions_update(
+                        0,
+                        allowlist_join_condition_id,
+                        JoinConditionPolicy::AllowApplication,
+                    )
+                });
+            client
+                .call(&ink_e2e::alice(), group_join_conditions_update, 0, None)
+                .await
+                .expect("group_join_conditions_update failed");
 
-        // === HELPERS ===
-        fn init() -> (DefaultAccounts<DefaultEnvironment>, AZGroups) {
-            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            let az_groups = AZGroups::new();
-            (accounts, az_groups)
-        }
+            // group_join_conditions_show
+            let group_join_conditions_show = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_join_conditions_show(0));
+            let group_join_condition: GroupJoinCondition = client
+                .call_dry_run(&ink_e2e::bob(), &group_join_conditions_show, 0, None)
+                .await
+                .return_value()
+                .unwrap();
+            assert_eq!(group_join_condition.address, allowlist_join_condition_id);
 
-        // === TEST HANDLES ===
-        #[ink::test]
-        fn test_group_users_create() {
-            let (accounts, mut az_groups) = init();
-            let group_name: String = MOCK_GROUP_NAME.to_string();
-            // when group with id does not exist
-            // * it raises an error
-            let mut result = az_groups.group_users_create(0);
-            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
-            // when group with id exists
-            az_groups.groups_create(group_name).unwrap();
-            // = when GroupUser exists
-            result = az_groups.group_users_create(0);
-            // = * it raises an error
+            // group_users_create
+            let group_users_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_users_create(0));
+            // when applicant doesn't meet the join condition
+            let result = client
+                .call_dry_run(&ink_e2e::charlie(), &group_users_create, 0, None)
+                .await
+                .return_value();
             assert_eq!(
                 result,
                 Err(AZGroupsError::UnprocessableEntity(
-                    "Group user has already been taken".to_string()
+                    "Join condition not met".to_string()
                 ))
             );
-            // = when GroupUser doesn't exist
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
-            // = * it creates the group user with the role applicant
-            result = az_groups.group_users_create(0);
+            // when applicant meets the join condition
+            // = when policy is AllowApplication
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &group_users_create, 0, None)
+                .await
+                .return_value();
             assert_eq!(result.unwrap().role, Role::Applicant);
+            // = when policy is AutoApprove
+            let group_join_conditions_update =
+                build_message::<AZGroupsRef>(az_groups_id).call(|az_groups| {
+                    az_groups.group_join_conditions_update(
+                        0,
+                        allowlist_join_condition_id,
+                        JoinConditionPolicy::AutoApprove,
+                    )
+                });
+            client
+                .call(&ink_e2e::alice(), group_join_conditions_update, 0, None)
+                .await
+                .expect("group_join_conditions_update failed");
+            let result = client
+                .call(&ink_e2e::bob(), group_users_create, 0, None)
+                .await
+                .expect("group_users_create failed");
+            assert_eq!(result.return_value().unwrap().role, Role::Member);
+
+            // group_join_conditions_destroy
+            let group_join_conditions_destroy = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_join_conditions_destroy(0));
+            client
+                .call(&ink_e2e::alice(), group_join_conditions_destroy, 0, None)
+                .await
+                .expect("group_join_conditions_destroy failed");
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &group_join_conditions_show, 0, None)
+                .await
+                .return_value();
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("GroupJoinCondition".to_string()))
+            );
+
+            Ok(())
         }
 
-        #[ink::test]
-        fn test_group_users_destroy() {
-            let (accounts, mut az_groups) = init();
-            let group_name: String = MOCK_GROUP_NAME.to_string();
-            //  when group with key exists
-            az_groups.groups_create(group_name.clone()).unwrap();
-            // = when caller does not have a group user for team
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
-            let mut result = az_groups.group_users_destroy(0, accounts.bob);
-            // = * it raises an error
+        #[ink_e2e::test]
+        async fn e2e_group_observers(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let az_groups_id = client
+                .instantiate("az_groups", &ink_e2e::alice(), AZGroupsRef::new(), 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            // An account without a contract, so that every callback fails
+            let observer: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Dave);
+            let groups_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_create("The Next Wave".to_string()));
+            client
+                .call(&ink_e2e::alice(), groups_create, 0, None)
+                .await
+                .expect("groups_create failed");
+
+            // group_observers_create
+            let group_observers_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_observers_create(0, observer));
+            client
+                .call(&ink_e2e::alice(), group_observers_create, 0, None)
+                .await
+                .expect("group_observers_create failed");
+
+            // group_observers_index
+            let group_observers_index = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_observers_index(0));
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &group_observers_index, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, vec![observer]);
+
+            // when a callback fails
+            // * it doesn't revert the change
+            // * it emits a GroupObserverFailure event
+            let group_users_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_users_create(0));
+            let result = client
+                .call(&ink_e2e::bob(), group_users_create, 0, None)
+                .await
+                .expect("group_users_create failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert!(matches!(
+                &events[..],
+                [
+                    Event::GroupUserCreate(_),
+                    Event::GroupObserverFailure(GroupObserverFailure { group_id: 0, observer: failed_observer, selector })
+                ] if *failed_observer == observer
+                    && *selector == ink::selector_bytes!("GroupObserver::on_group_user_create")
+            ));
+
+            // group_observers_destroy
+            let group_observers_destroy = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_observers_destroy(0, observer));
+            client
+                .call(&ink_e2e::alice(), group_observers_destroy, 0, None)
+                .await
+                .expect("group_observers_destroy failed");
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &group_observers_index, 0, None)
+                .await
+                .return_value();
+            assert!(result.is_empty());
+
+            Ok(())
+        }
+
+        #[ink_e2e::test(additional_contracts = "test_contracts/mock_domain_registry/Cargo.toml")]
+        async fn e2e_group_domains(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let az_groups_id = client
+                .instantiate("az_groups", &ink_e2e::alice(), AZGroupsRef::new(), 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let bob: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let mock_domain_registry_id = client
+                .instantiate(
+                    "mock_domain_registry",
+                    &ink_e2e::alice(),
+                    MockDomainRegistryRef::new(),
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let groups_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_create("The Next Wave".to_string()));
+            client
+                .call(&ink_e2e::alice(), groups_create, 0, None)
+                .await
+                .expect("groups_create failed");
+            for (caller, domain) in [
+                (ink_e2e::alice(), "wave.azero"),
+                (ink_e2e::bob(), "bob.azero"),
+            ] {
+                let register = build_message::<MockDomainRegistryRef>(mock_domain_registry_id)
+                    .call(|mock_domain_registry| mock_domain_registry.register(domain.to_string()));
+                client
+                    .call(&caller, register, 0, None)
+                    .await
+                    .expect("register failed");
+            }
+
+            // domain_registries_update
+            let domain_registries_update =
+                build_message::<AZGroupsRef>(az_groups_id).call(|az_groups| {
+                    az_groups.domain_registries_update(Some(mock_domain_registry_id))
+                });
+            client
+                .call(&ink_e2e::alice(), domain_registries_update, 0, None)
+                .await
+                .expect("domain_registries_update failed");
+
+            // group_domains_update
+            // when the account doesn't own the domain
+            let group_domains_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_domains_update(0, "bob.azero".to_string(), None));
+            let result = client
+                .call_dry_run(&ink_e2e::alice(), &group_domains_update, 0, None)
+                .await
+                .return_value();
             assert_eq!(
                 result,
-                Err(AZGroupsError::NotFound("GroupUser".to_string()))
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Domain isn't owned by the account".to_string()
+                ))
             );
-            // = when caller has a group user for team
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            // == when user does not have a group user for team
-            result = az_groups.group_users_destroy(0, accounts.charlie);
-            // == * it raises an error
+            // when the owner of the domain isn't a super admin of the group
+            let group_domains_update =
+                build_message::<AZGroupsRef>(az_groups_id).call(|az_groups| {
+                    az_groups.group_domains_update(0, "bob.azero".to_string(), Some(bob))
+                });
+            let result = client
+                .call_dry_run(&ink_e2e::alice(), &group_domains_update, 0, None)
+                .await
+                .return_value();
             assert_eq!(
                 result,
                 Err(AZGroupsError::NotFound("GroupUser".to_string()))
             );
-            // == when user has a group user for team
-            // === when caller equals user
-            // ==== when role is super admin
-            // ==== * it raises an error
-            result = az_groups.group_users_destroy(0, accounts.bob);
-            assert_eq!(result, Err(AZGroupsError::Unauthorised));
-            // ==== when role is not super admin
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
-            // ===== when role is not banned
-            az_groups.group_users_create(0).unwrap();
-            // ===== * it destroys UserGroup
-            az_groups.group_users_destroy(0, accounts.charlie).unwrap();
-            assert!(az_groups.group_users.get((0, accounts.charlie)).is_none());
-            // ===== when role is banned
-            az_groups.group_users_create(0).unwrap();
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            az_groups
-                .group_users_update(0, accounts.charlie, Role::Banned)
-                .unwrap();
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
-            // ===== * it raises an error
-            result = az_groups.group_users_destroy(0, accounts.charlie);
-            assert_eq!(result, Err(AZGroupsError::Unauthorised));
-            // === when caller does not equal user
-            // ==== when caller role is less than 3 (less than admin)
-            // ==== * it raises an error
-            result = az_groups.group_users_destroy(0, accounts.bob);
-            assert_eq!(result, Err(AZGroupsError::Unauthorised));
-            // ==== when caller role is greater than or equal to 3
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            az_groups
-                .group_users_update(0, accounts.charlie, Role::Admin)
-                .unwrap();
-            // ===== when caller's role is less than user's role
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
-            // ===== * it raises an error
-            result = az_groups.group_users_destroy(0, accounts.bob);
-            assert_eq!(result, Err(AZGroupsError::Unauthorised));
-            // ===== when caller's role is greater than or equal to user's role
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            az_groups
-                .group_users_update(0, accounts.charlie, Role::SuperAdmin)
+            // when the caller owns the domain
+            let group_domains_update =
+                build_message::<AZGroupsRef>(az_groups_id).call(|az_groups| {
+                    az_groups.group_domains_update(0, "Wave.azero".to_string(), None)
+                });
+            let result = client
+                .call(&ink_e2e::alice(), group_domains_update, 0, None)
+                .await
+                .expect("group_domains_update failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert!(matches!(
+                &events[..],
+                [Event::GroupDomainUpdate(GroupDomainUpdate { group_id: 0, domain: Some(domain) })]
+                    if domain == "wave.azero"
+            ));
+
+            // groups_show
+            let groups_show = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_show(0));
+            let group: Group = client
+                .call_dry_run(&ink_e2e::bob(), &groups_show, 0, None)
+                .await
+                .return_value()
                 .unwrap();
-            // ===== * it destroys UserGroup
-            az_groups.group_users_destroy(0, accounts.charlie).unwrap();
-            assert!(az_groups.group_users.get((0, accounts.charlie)).is_none());
+            assert_eq!(group.domain, Some("wave.azero".to_string()));
+
+            Ok(())
         }
 
-        #[ink::test]
-        fn test_group_users_update() {
-            let (accounts, mut az_groups) = init();
-            let group_name: String = MOCK_GROUP_NAME.to_string();
-            // = when group with key exists
-            az_groups.groups_create(group_name).unwrap();
-            // == when caller equals user
-            // == * it raises an error
-            let mut result = az_groups.group_users_update(0, accounts.bob, Role::SuperAdmin);
-            assert_eq!(result, Err(AZGroupsError::Unauthorised));
-            // == when caller is different to user
-            // === when caller does not have a group user for team
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
-            result = az_groups.group_users_update(0, accounts.bob, Role::SuperAdmin);
-            // === * it raises an error
+        #[ink_e2e::test(additional_contracts = "test_contracts/membership_consumer/Cargo.toml")]
+        async fn e2e_membership_consumer(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let az_groups_id = client
+                .instantiate("az_groups", &ink_e2e::alice(), AZGroupsRef::new(), 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let membership_consumer_id = client
+                .instantiate(
+                    "membership_consumer",
+                    &ink_e2e::alice(),
+                    MembershipConsumerRef::new(az_groups_id),
+                    0,
+                    None,
+                )
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let groups_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_create("The Next Wave".to_string()));
+            client
+                .call(&ink_e2e::alice(), groups_create, 0, None)
+                .await
+                .expect("groups_create failed");
+
+            let caller_role = build_message::<MembershipConsumerRef>(membership_consumer_id)
+                .call(|membership_consumer| membership_consumer.caller_role(0));
+            // when caller is a member
+            let result: Result<Role, AZGroupsError> = return_value(
+                client
+                    .call_dry_run(&ink_e2e::alice(), &caller_role, 0, None)
+                    .await
+                    .return_data(),
+            );
+            assert_eq!(result, Ok(Role::SuperAdmin));
+            // when caller is not part of the group
+            let result: Result<Role, AZGroupsError> = return_value(
+                client
+                    .call_dry_run(&ink_e2e::bob(), &caller_role, 0, None)
+                    .await
+                    .return_data(),
+            );
             assert_eq!(
                 result,
                 Err(AZGroupsError::NotFound("GroupUser".to_string()))
             );
-            // === when caller has a group user for team
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            // ==== when caller's role is less than 3
-            let mut caller_group_user: GroupUser =
-                az_groups.group_users.get((0, accounts.bob)).unwrap();
-            caller_group_user.role = Role::Member;
-            az_groups
-                .group_users
-                .insert((0, accounts.bob), &caller_group_user);
-            // ==== * it raises an error
-            result = az_groups.group_users_update(0, accounts.bob, Role::Member);
-            assert_eq!(result, Err(AZGroupsError::Unauthorised));
-            // ==== when caller's role is 3 or more
-            caller_group_user.role = Role::Admin;
-            az_groups
-                .group_users
-                .insert((0, accounts.bob), &caller_group_user);
-            // ===== when user does not have a group user for team
-            result = az_groups.group_users_update(0, accounts.charlie, Role::SuperAdmin);
-            // ===== * it raises an error
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_proposals(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let az_groups_id = client
+                .instantiate("az_groups", &ink_e2e::alice(), AZGroupsRef::new(), 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let bob: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let groups_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_create("The Next Wave".to_string()));
+            client
+                .call(&ink_e2e::alice(), groups_create, 0, None)
+                .await
+                .expect("groups_create failed");
+            let group_users_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_users_create(0));
+            client
+                .call(&ink_e2e::bob(), group_users_create, 0, None)
+                .await
+                .expect("group_users_create failed");
+            let group_users_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_users_update(0, bob, Role::Member));
+            client
+                .call(&ink_e2e::alice(), group_users_update, 0, None)
+                .await
+                .expect("group_users_update failed");
+            let action = ProposalAction::GroupsUpdate {
+                name: "King Kong".to_string(),
+                enabled: true,
+            };
+
+            // proposals_create
+            // when the group doesn't have governance settings
+            let proposals_create = build_message::<AZGroupsRef>(az_groups_id).call(|az_groups| {
+                az_groups.proposals_create(0, Hash::from([1; 32]), action.clone())
+            });
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &proposals_create, 0, None)
+                .await
+                .return_value();
             assert_eq!(
                 result,
-                Err(AZGroupsError::NotFound("GroupUser".to_string()))
+                Err(AZGroupsError::NotFound("GroupGovernance".to_string()))
             );
-            // ===== when user has a role with team
-            // ====== when caller's role is less than user's role
-            let mut user_group_user: GroupUser = GroupUser {
-                role: Role::SuperAdmin,
-            };
-            az_groups
-                .group_users
-                .insert((0, accounts.charlie), &user_group_user);
-            // ====== * it raises an error
-            result = az_groups.group_users_update(0, accounts.charlie, Role::SuperAdmin);
-            assert_eq!(result, Err(AZGroupsError::Unauthorised));
-            // ====== when caller's role is greater than or equal to user's role
-            user_group_user = GroupUser { role: Role::Admin };
-            az_groups
-                .group_users
-                .insert((0, accounts.charlie), &user_group_user);
-            // ======= when new role is less than or equal to caller's role
-            // ======= * it updates the user's role
-            result = az_groups.group_users_update(0, accounts.charlie, Role::Admin);
-            assert_eq!(result.unwrap().role, Role::Admin);
-            // ======= when new role is greater than caller's role
-            // ======= * it raises an error
-            result = az_groups.group_users_update(0, accounts.charlie, Role::SuperAdmin);
+            // when the group has governance settings
+            let group_governances_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_governances_update(0, 2, 51, 86_400_000));
+            client
+                .call(&ink_e2e::alice(), group_governances_update, 0, None)
+                .await
+                .expect("group_governances_update failed");
+            let result = client
+                .call(&ink_e2e::bob(), proposals_create, 0, None)
+                .await
+                .expect("proposals_create failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert!(matches!(
+                &events[..],
+                [Event::ProposalCreate(ProposalCreate { id: 0, group_id: 0, proposer, action: event_action })]
+                    if *proposer == bob && *event_action == action
+            ));
+
+            // proposal_votes_create
+            // when the quorum hasn't been reached
+            let proposal_votes_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.proposal_votes_create(0, true));
+            let result = client
+                .call(&ink_e2e::bob(), proposal_votes_create, 0, None)
+                .await
+                .expect("proposal_votes_create failed");
+            assert_eq!(
+                result.return_value().unwrap().status,
+                ProposalStatus::Active
+            );
+            // when caller has already voted
+            let proposal_votes_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.proposal_votes_create(0, true));
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &proposal_votes_create, 0, None)
+                .await
+                .return_value();
+            assert_eq!(
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Vote has already been taken".to_string()
+                ))
+            );
+            // when the quorum and threshold are reached
+            let result = client
+                .call(&ink_e2e::alice(), proposal_votes_create, 0, None)
+                .await
+                .expect("proposal_votes_create failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert!(matches!(
+                events.last(),
+                Some(Event::ProposalExecute(ProposalExecute {
+                    id: 0,
+                    group_id: 0,
+                    status: ProposalStatus::Executed
+                }))
+            ));
+
+            // proposals_show
+            let proposals_show = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.proposals_show(0));
+            let proposal: Proposal = client
+                .call_dry_run(&ink_e2e::charlie(), &proposals_show, 0, None)
+                .await
+                .return_value()
+                .unwrap();
+            assert_eq!(proposal.votes_for, 2);
+            assert_eq!(proposal.status, ProposalStatus::Executed);
+            let groups_show = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_show(0));
+            let group: Group = client
+                .call_dry_run(&ink_e2e::charlie(), &groups_show, 0, None)
+                .await
+                .return_value()
+                .unwrap();
+            assert_eq!(group.name, "King Kong");
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_petitions(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let az_groups_id = client
+                .instantiate("az_groups", &ink_e2e::alice(), AZGroupsRef::new(), 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let alice: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+            let bob: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let charlie: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie);
+            let groups_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_create("The Next Wave".to_string()));
+            client
+                .call(&ink_e2e::alice(), groups_create, 0, None)
+                .await
+                .expect("groups_create failed");
+            for (signer, user, role) in [
+                (ink_e2e::bob(), bob, Role::Member),
+                (ink_e2e::charlie(), charlie, Role::Admin),
+            ] {
+                let group_users_create = build_message::<AZGroupsRef>(az_groups_id)
+                    .call(|az_groups| az_groups.group_users_create(0));
+                client
+                    .call(&signer, group_users_create, 0, None)
+                    .await
+                    .expect("group_users_create failed");
+                let group_users_update = build_message::<AZGroupsRef>(az_groups_id)
+                    .call(|az_groups| az_groups.group_users_update(0, user, role.clone()));
+                client
+                    .call(&ink_e2e::alice(), group_users_update, 0, None)
+                    .await
+                    .expect("group_users_update failed");
+            }
+            let group_impeachments_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_impeachments_update(0, 50, 0, 86_400_000));
+            client
+                .call(&ink_e2e::alice(), group_impeachments_update, 0, None)
+                .await
+                .expect("group_impeachments_update failed");
+
+            // petitions_create
+            // when target is a super admin and the group doesn't allow it
+            let petitions_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.petitions_create(0, alice, PetitionAction::Demote));
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &petitions_create, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when target is an admin
+            let petitions_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.petitions_create(0, charlie, PetitionAction::Demote));
+            let result = client
+                .call(&ink_e2e::bob(), petitions_create, 0, None)
+                .await
+                .expect("petitions_create failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert!(matches!(
+                &events[..],
+                [
+                    Event::PetitionCreate(PetitionCreate { id: 0, group_id: 0, target, action: PetitionAction::Demote }),
+                    Event::PetitionSignatureCreate(PetitionSignatureCreate { petition_id: 0, signer })
+                ] if *target == charlie && *signer == bob
+            ));
+
+            // petition_signatures_create
+            // when caller is the target
+            let petition_signatures_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.petition_signatures_create(0));
+            let result = client
+                .call_dry_run(&ink_e2e::charlie(), &petition_signatures_create, 0, None)
+                .await
+                .return_value();
             assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when the threshold is reached
+            let result = client
+                .call(&ink_e2e::alice(), petition_signatures_create, 0, None)
+                .await
+                .expect("petition_signatures_create failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert!(matches!(
+                events.last(),
+                Some(Event::PetitionExecute(PetitionExecute {
+                    id: 0,
+                    group_id: 0,
+                    target,
+                    status: PetitionStatus::Executed
+                })) if *target == charlie
+            ));
+
+            // petitions_show
+            let petitions_show = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.petitions_show(0));
+            let petition: Petition = client
+                .call_dry_run(&ink_e2e::bob(), &petitions_show, 0, None)
+                .await
+                .return_value()
+                .unwrap();
+            assert_eq!(petition.signatures, 2);
+            assert_eq!(petition.status, PetitionStatus::Executed);
+            let group_users_show = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_users_show(0, charlie));
+            let group_user: GroupUser = client
+                .call_dry_run(&ink_e2e::bob(), &group_users_show, 0, None)
+                .await
+                .return_value()
+                .unwrap();
+            assert_eq!(group_user.role, Role::Member);
+
+            Ok(())
         }
 
-        #[ink::test]
-        fn test_groups_create() {
-            let (accounts, mut az_groups) = init();
-            let group_name: String = MOCK_GROUP_NAME.to_string();
-            let key: String = group_name.to_lowercase();
-            // when group with key does not exist
-            // * it creates the group with the supplied name
-            // * it sets the group to enabled
-            // * it returns the created group
-            let mut result = az_groups.groups_create(group_name.clone());
-            let group = result.unwrap();
-            assert_eq!(group.name, group_name);
-            assert_eq!(group.enabled, true);
-            // * it creates and sets a new GroupUser with the caller as super admin
-            let group_user: GroupUser = az_groups.group_users.get((0, accounts.bob)).unwrap();
-            assert_eq!(group_user.role, Role::SuperAdmin);
-            // * it sets the group_id_by_name
-            assert_eq!(az_groups.group_id_by_name.get(key.clone()).unwrap(), 0);
-            // * it increases the groups total by one
-            assert_eq!(az_groups.groups_total, 1);
-            // when group with key already exists
-            // * it raises an error
-            result = az_groups.groups_create(key);
+        #[ink_e2e::test]
+        async fn e2e_polls(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let az_groups_id = client
+                .instantiate("az_groups", &ink_e2e::alice(), AZGroupsRef::new(), 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let bob: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let groups_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_create("The Next Wave".to_string()));
+            client
+                .call(&ink_e2e::alice(), groups_create, 0, None)
+                .await
+                .expect("groups_create failed");
+            let group_users_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_users_create(0));
+            client
+                .call(&ink_e2e::bob(), group_users_create, 0, None)
+                .await
+                .expect("group_users_create failed");
+            let group_users_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_users_update(0, bob, Role::Member));
+            client
+                .call(&ink_e2e::alice(), group_users_update, 0, None)
+                .await
+                .expect("group_users_update failed");
+
+            // polls_create
+            // when caller is not an admin
+            let polls_create = build_message::<AZGroupsRef>(az_groups_id).call(|az_groups| {
+                az_groups.polls_create(0, Hash::from([1; 32]), 2, 0, Timestamp::MAX)
+            });
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &polls_create, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is an admin
+            let result = client
+                .call(&ink_e2e::alice(), polls_create, 0, None)
+                .await
+                .expect("polls_create failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert!(matches!(
+                &events[..],
+                [Event::PollCreate(PollCreate {
+                    id: 0,
+                    group_id: 0,
+                    options: 2,
+                    ..
+                })]
+            ));
+
+            // poll_votes_create
+            // when option is invalid
+            let poll_votes_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.poll_votes_create(0, 2));
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &poll_votes_create, 0, None)
+                .await
+                .return_value();
             assert_eq!(
                 result,
                 Err(AZGroupsError::UnprocessableEntity(
-                    "Group has already been taken".to_string()
+                    "Option is invalid".to_string()
                 ))
             );
-            // when groups_total is u32 max
-            az_groups.groups_total = u32::MAX;
-            // * it raises an error
-            result = az_groups.groups_create("XXXX".to_string());
+            // when option is valid
+            let poll_votes_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.poll_votes_create(0, 1));
+            let result = client
+                .call(&ink_e2e::bob(), poll_votes_create, 0, None)
+                .await
+                .expect("poll_votes_create failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert!(matches!(
+                &events[..],
+                [Event::PollVoteCreate(PollVoteCreate { poll_id: 0, voter, option: 1 })]
+                    if *voter == bob
+            ));
+            let poll_votes_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.poll_votes_create(0, 0));
+            client
+                .call(&ink_e2e::alice(), poll_votes_create, 0, None)
+                .await
+                .expect("poll_votes_create failed");
+
+            // polls_show
+            let polls_show = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.polls_show(0));
+            let poll: Poll = client
+                .call_dry_run(&ink_e2e::charlie(), &polls_show, 0, None)
+                .await
+                .return_value()
+                .unwrap();
+            assert_eq!(poll.tallies, vec![1, 1]);
+
+            Ok(())
+        }
+
+        // Voting only opens once the nomination period has ended, so only the checks before that are covered here.
+        #[ink_e2e::test]
+        async fn e2e_elections(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let az_groups_id = client
+                .instantiate("az_groups", &ink_e2e::alice(), AZGroupsRef::new(), 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let alice: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+            let bob: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let groups_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_create("The Next Wave".to_string()));
+            client
+                .call(&ink_e2e::alice(), groups_create, 0, None)
+                .await
+                .expect("groups_create failed");
+            let group_users_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_users_create(0));
+            client
+                .call(&ink_e2e::bob(), group_users_create, 0, None)
+                .await
+                .expect("group_users_create failed");
+            let group_users_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_users_update(0, bob, Role::Member));
+            client
+                .call(&ink_e2e::alice(), group_users_update, 0, None)
+                .await
+                .expect("group_users_update failed");
+
+            // elections_create
+            let elections_create = build_message::<AZGroupsRef>(az_groups_id).call(|az_groups| {
+                az_groups.elections_create(0, 1, 86_400_000, 86_400_000, 86_400_000)
+            });
+            let result = client
+                .call(&ink_e2e::alice(), elections_create, 0, None)
+                .await
+                .expect("elections_create failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert!(matches!(
+                &events[..],
+                [Event::ElectionCreate(ElectionCreate {
+                    id: 0,
+                    group_id: 0,
+                    seats: 1,
+                    ..
+                })]
+            ));
+            // when an election is already in progress
+            let elections_create = build_message::<AZGroupsRef>(az_groups_id).call(|az_groups| {
+                az_groups.elections_create(0, 1, 86_400_000, 86_400_000, 86_400_000)
+            });
+            let result = client
+                .call_dry_run(&ink_e2e::alice(), &elections_create, 0, None)
+                .await
+                .return_value();
             assert_eq!(
                 result,
                 Err(AZGroupsError::UnprocessableEntity(
-                    "Group limit reached".to_string()
+                    "Election is already in progress".to_string()
                 ))
             );
-            // when group_name is blank
-            // * it raises an error
-            result = az_groups.groups_create(" ".to_string());
+
+            // election_nominations_create
+            // when candidate is not a member
+            let election_nominations_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.election_nominations_create(0, alice));
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &election_nominations_create, 0, None)
+                .await
+                .return_value();
             assert_eq!(
                 result,
                 Err(AZGroupsError::UnprocessableEntity(
-                    "Name can't be blank".to_string()
+                    "Candidate must be a member".to_string()
                 ))
             );
-        }
-
-        #[ink::test]
-        fn test_groups_find_by_name() {
-            let (_accounts, mut az_groups) = init();
-            let group_name: String = MOCK_GROUP_NAME.to_string();
-            // when group with name does not exist
-            // * it raises an error
-            let mut result = az_groups.groups_find_by_name(group_name.clone());
-            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
-            // when group with name exists
-            az_groups.groups_create(group_name.clone()).unwrap();
-            // = when name with no matching key is provided
-            // = * it raises an error
-            result = az_groups.groups_find_by_name("asdf".to_string());
-            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
-            // = when name with a matching key is provided (case insensitive)
-            // = * it returns the group
-            result = az_groups.groups_find_by_name(group_name.to_uppercase());
-            assert_eq!(result.unwrap(), az_groups.groups.get(0).unwrap());
-        }
+            // when candidate is a member
+            let election_nominations_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.election_nominations_create(0, bob));
+            let result = client
+                .call(&ink_e2e::alice(), election_nominations_create, 0, None)
+                .await
+                .expect("election_nominations_create failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert!(matches!(
+                &events[..],
+                [Event::ElectionNominationCreate(ElectionNominationCreate { election_id: 0, candidate, nominator })]
+                    if *candidate == bob && *nominator == alice
+            ));
 
-        #[ink::test]
-        fn test_groups_update() {
-            let (accounts, mut az_groups) = init();
-            let group_name: String = MOCK_GROUP_NAME.to_string();
-            let key: String = group_name.to_lowercase();
-            // when group with key does not exist
-            // * it raises an error
-            let mut result = az_groups.groups_update(0, group_name.clone(), true);
-            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
-            // when group with key exists
-            az_groups.groups_create(group_name.clone()).unwrap();
-            // = when caller is not part of group
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
-            // = * it raises an error
-            result = az_groups.groups_update(0, group_name.clone(), true);
+            // election_votes_create
+            // when nominations haven't closed
+            let election_votes_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.election_votes_create(0, bob));
+            let result = client
+                .call_dry_run(&ink_e2e::alice(), &election_votes_create, 0, None)
+                .await
+                .return_value();
             assert_eq!(
                 result,
-                Err(AZGroupsError::NotFound("GroupUser".to_string()))
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Voting has not started".to_string()
+                ))
             );
-            // = when caller is part of group
-            az_groups.group_users_create(0).unwrap();
-            // == when caller is not a super admin
-            // == * it raises an error
-            result = az_groups.groups_update(0, group_name.clone(), true);
-            assert_eq!(result, Err(AZGroupsError::Unauthorised));
-            // == when caller is a super admin
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
-            // === when new_name is present
-            // ==== when new_name is empty blank
-            // ==== * it raises an error
-            result = az_groups.groups_update(0, " ".to_string(), false);
+
+            // elections_close
+            // when voting hasn't ended
+            let elections_close = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.elections_close(0));
+            let result = client
+                .call_dry_run(&ink_e2e::charlie(), &elections_close, 0, None)
+                .await
+                .return_value();
             assert_eq!(
                 result,
                 Err(AZGroupsError::UnprocessableEntity(
-                    "Name can't be blank".to_string()
+                    "Voting has not ended".to_string()
                 ))
             );
-            // ==== when new_name is available
-            // ==== * it updates the group
-            let mut new_name: String = "King Kong".to_string();
-            result = az_groups.groups_update(0, new_name.clone(), false);
+
+            // elections_show
+            let elections_show = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.elections_show(0));
+            let election: Election = client
+                .call_dry_run(&ink_e2e::charlie(), &elections_show, 0, None)
+                .await
+                .return_value()
+                .unwrap();
             assert_eq!(
-                result.unwrap(),
-                Group {
-                    id: 0,
-                    name: new_name.clone(),
-                    enabled: false
-                }
+                election.candidates,
+                vec![ElectionCandidate {
+                    user: bob,
+                    votes: 0
+                }]
             );
-            // ==== * it removes the old group_id_by_name map
-            assert!(az_groups.group_id_by_name.get(key).is_none());
-            // ==== * it create the new group_id_by_name map
+            assert!(!election.closed);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_vouches(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let az_groups_id = client
+                .instantiate("az_groups", &ink_e2e::alice(), AZGroupsRef::new(), 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let alice: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+            let bob: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let charlie: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie);
+            let groups_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_create("The Next Wave".to_string()));
+            client
+                .call(&ink_e2e::alice(), groups_create, 0, None)
+                .await
+                .expect("groups_create failed");
+            for signer in [ink_e2e::bob(), ink_e2e::charlie()] {
+                let group_users_create = build_message::<AZGroupsRef>(az_groups_id)
+                    .call(|az_groups| az_groups.group_users_create(0));
+                client
+                    .call(&signer, group_users_create, 0, None)
+                    .await
+                    .expect("group_users_create failed");
+            }
+            let group_users_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_users_update(0, bob, Role::Member));
+            client
+                .call(&ink_e2e::alice(), group_users_update, 0, None)
+                .await
+                .expect("group_users_update failed");
+
+            // vouch
+            // when vouching isn't enabled
+            let vouch = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.vouch(0, charlie));
+            let result = client
+                .call_dry_run(&ink_e2e::alice(), &vouch, 0, None)
+                .await
+                .return_value();
             assert_eq!(
-                az_groups
-                    .group_id_by_name
-                    .get(new_name.to_lowercase())
-                    .unwrap(),
-                0
+                result,
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Vouching is not enabled".to_string()
+                ))
             );
-            // ==== when new_name is taken
-            // ===== when new_name's key is the same as the original key
-            new_name = new_name.to_uppercase() + " ";
-            result = az_groups.groups_update(0, new_name.clone(), true);
-            // ===== * it updates
+            // when vouching is enabled
+            let group_vouch_thresholds_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_vouch_thresholds_update(0, 2));
+            client
+                .call(&ink_e2e::alice(), group_vouch_thresholds_update, 0, None)
+                .await
+                .expect("group_vouch_thresholds_update failed");
+            let result = client
+                .call(&ink_e2e::alice(), vouch, 0, None)
+                .await
+                .expect("vouch failed");
+            assert_eq!(result.return_value(), Ok(vec![alice]));
+
+            // vouches_index
+            let vouches_index = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.vouches_index(0, charlie));
+            let result = client
+                .call_dry_run(&ink_e2e::charlie(), &vouches_index, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, vec![alice]);
+
+            // vouches_destroy
+            let vouches_destroy = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.vouches_destroy(0, charlie));
+            let result = client
+                .call(&ink_e2e::alice(), vouches_destroy, 0, None)
+                .await
+                .expect("vouches_destroy failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert!(matches!(
+                &events[..],
+                [Event::VouchDestroy(VouchDestroy { group_id: 0, applicant, voucher })]
+                    if *applicant == charlie && *voucher == alice
+            ));
+
+            // vouch
+            // when the threshold is reached
+            // * it makes the applicant a member
+            for signer in [ink_e2e::alice(), ink_e2e::bob()] {
+                let vouch = build_message::<AZGroupsRef>(az_groups_id)
+                    .call(|az_groups| az_groups.vouch(0, charlie));
+                client
+                    .call(&signer, vouch, 0, None)
+                    .await
+                    .expect("vouch failed");
+            }
+            let validate_membership = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.validate_membership(0, charlie));
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &validate_membership, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, Ok(Role::Member));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_pending_operations(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let az_groups_id = client
+                .instantiate("az_groups", &ink_e2e::alice(), AZGroupsRef::new(), 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let alice: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+            let bob: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let groups_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_create("The Next Wave".to_string()));
+            client
+                .call(&ink_e2e::alice(), groups_create, 0, None)
+                .await
+                .expect("groups_create failed");
+            let group_users_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_users_create(0));
+            client
+                .call(&ink_e2e::bob(), group_users_create, 0, None)
+                .await
+                .expect("group_users_create failed");
+            let group_users_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_users_update(0, bob, Role::SuperAdmin));
+            client
+                .call(&ink_e2e::alice(), group_users_update, 0, None)
+                .await
+                .expect("group_users_update failed");
+            let group_multisigs_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_multisigs_update(0, 2));
+            client
+                .call(&ink_e2e::alice(), group_multisigs_update, 0, None)
+                .await
+                .expect("group_multisigs_update failed");
+
+            // when a super admin changes the slug of a group with a multisig
+            // * it creates a pending operation confirmed by the super admin
+            let group_slugs_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_slugs_update(0, "wave".to_string()));
+            let result = client
+                .call(&ink_e2e::alice(), group_slugs_update, 0, None)
+                .await
+                .expect("group_slugs_update failed");
+            assert_eq!(result.return_value(), Ok("the-next-wave".to_string()));
+
+            // pending_operations_index
+            let pending_operations_index = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.pending_operations_index(0));
+            let result = client
+                .call_dry_run(&ink_e2e::charlie(), &pending_operations_index, 0, None)
+                .await
+                .return_value();
             assert_eq!(
-                result.unwrap(),
-                Group {
+                result,
+                vec![PendingOperation {
                     id: 0,
-                    name: AZGroups::format_group_name(new_name),
-                    enabled: true
-                }
+                    group_id: 0,
+                    proposer: alice,
+                    action: PendingOperationAction::GroupSlugsUpdate {
+                        slug: "wave".to_string()
+                    },
+                    confirmations: vec![alice],
+                }]
             );
-            // ===== when new_name's key is different from the original key
-            az_groups.group_id_by_name.insert("a".to_string(), &1);
-            result = az_groups.groups_update(0, "A".to_string(), true);
-            // ===== * it raises an error
+
+            // pending_operations_revoke
+            // * it removes the operation once it has no confirmations left
+            let pending_operations_revoke = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.pending_operations_revoke(0));
+            client
+                .call(&ink_e2e::alice(), pending_operations_revoke, 0, None)
+                .await
+                .expect("pending_operations_revoke failed");
+            let result = client
+                .call_dry_run(&ink_e2e::charlie(), &pending_operations_index, 0, None)
+                .await
+                .return_value();
+            assert!(result.is_empty());
+
+            // pending_operations_confirm
+            // when the operation has enough confirmations
+            // * it carries out the operation
+            let group_slugs_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_slugs_update(0, "wave".to_string()));
+            client
+                .call(&ink_e2e::alice(), group_slugs_update, 0, None)
+                .await
+                .expect("group_slugs_update failed");
+            let pending_operations_confirm = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.pending_operations_confirm(1));
+            let result = client
+                .call(&ink_e2e::bob(), pending_operations_confirm, 0, None)
+                .await
+                .expect("pending_operations_confirm failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert!(matches!(
+                &events[..],
+                [
+                    Event::PendingOperationConfirm(PendingOperationConfirm { id: 1, confirmer }),
+                    Event::GroupSlugUpdate(GroupSlugUpdate { group_id: 0, slug }),
+                    Event::PendingOperationExecute(PendingOperationExecute { id: 1, group_id: 0 })
+                ] if *confirmer == bob && slug == "wave"
+            ));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_pending_promotions(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let az_groups_id = client
+                .instantiate("az_groups", &ink_e2e::alice(), AZGroupsRef::new(), 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let alice: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+            let bob: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let groups_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_create("The Next Wave".to_string()));
+            client
+                .call(&ink_e2e::alice(), groups_create, 0, None)
+                .await
+                .expect("groups_create failed");
+            let group_users_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_users_create(0));
+            client
+                .call(&ink_e2e::bob(), group_users_create, 0, None)
+                .await
+                .expect("group_users_create failed");
+            let group_users_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_users_update(0, bob, Role::Member));
+            client
+                .call(&ink_e2e::alice(), group_users_update, 0, None)
+                .await
+                .expect("group_users_update failed");
+            let group_promotion_delays_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_promotion_delays_update(0, 86_400_000));
+            client
+                .call(&ink_e2e::alice(), group_promotion_delays_update, 0, None)
+                .await
+                .expect("group_promotion_delays_update failed");
+
+            // when a member is promoted to admin in a group with a promotion delay
+            // * it queues the promotion
+            let group_users_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_users_update(0, bob, Role::Admin));
+            let result = client
+                .call(&ink_e2e::alice(), group_users_update, 0, None)
+                .await
+                .expect("group_users_update failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert_eq!(result.return_value().unwrap().role, Role::Member);
+            assert!(matches!(
+                &events[..],
+                [Event::PendingPromotionCreate(PendingPromotionCreate { group_id: 0, user, role: Role::Admin, promoter, .. })]
+                    if *user == bob && *promoter == alice
+            ));
+
+            // pending_promotions_index
+            let pending_promotions_index = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.pending_promotions_index(0));
+            let result: Vec<PendingPromotion> = client
+                .call_dry_run(&ink_e2e::charlie(), &pending_promotions_index, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result.len(), 1);
+            assert_eq!(result[0].user, bob);
+            assert_eq!(result[0].role, Role::Admin);
+
+            // pending_promotions_execute
+            // when the delay hasn't passed
+            let pending_promotions_execute = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.pending_promotions_execute(0, bob));
+            let result = client
+                .call_dry_run(&ink_e2e::charlie(), &pending_promotions_execute, 0, None)
+                .await
+                .return_value();
             assert_eq!(
                 result,
                 Err(AZGroupsError::UnprocessableEntity(
-                    "Group has already been taken".to_string()
+                    "Promotion delay has not passed".to_string()
                 ))
             );
+
+            // pending_promotions_destroy
+            let pending_promotions_destroy = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.pending_promotions_destroy(0, bob));
+            let result = client
+                .call(&ink_e2e::alice(), pending_promotions_destroy, 0, None)
+                .await
+                .expect("pending_promotions_destroy failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert!(matches!(
+                &events[..],
+                [Event::PendingPromotionDestroy(PendingPromotionDestroy { group_id: 0, user, vetoer })]
+                    if *user == bob && *vetoer == alice
+            ));
+            let result = client
+                .call_dry_run(&ink_e2e::charlie(), &pending_promotions_index, 0, None)
+                .await
+                .return_value();
+            assert!(result.is_empty());
+
+            Ok(())
         }
 
-        #[ink::test]
-        fn test_validate_membership() {
-            let (accounts, mut az_groups) = init();
-            let group_name: String = MOCK_GROUP_NAME.to_string();
-            // when group with id does not exist
-            // * it raises an error
-            let mut result = az_groups.validate_membership(0, accounts.bob);
-            assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
-            // when group with id exists
-            let mut group: Group = az_groups.groups_create(group_name).unwrap();
-            // = when group is enabled
-            // == when GroupUser doesn't exist
-            result = az_groups.validate_membership(0, accounts.alice);
-            // = * it raises an error
+        #[ink_e2e::test]
+        async fn e2e_tags(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let az_groups_id = client
+                .instantiate("az_groups", &ink_e2e::alice(), AZGroupsRef::new(), 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let groups_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_create("The Next Wave".to_string()));
+            client
+                .call(&ink_e2e::alice(), groups_create, 0, None)
+                .await
+                .expect("groups_create failed");
+
+            // tags_create
+            // when caller is not the owner
+            let tags_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.tags_create(" DAO ".to_string()));
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &tags_create, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is the owner
+            let result = client
+                .call(&ink_e2e::alice(), tags_create, 0, None)
+                .await
+                .expect("tags_create failed");
+            assert_eq!(result.return_value(), Ok("dao".to_string()));
+
+            // tags_show
+            let tags_show = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.tags_show("Dao".to_string()));
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &tags_show, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, Ok("dao".to_string()));
+
+            // group_tags_update
+            // when a tag isn't in the vocabulary
+            let group_tags_update = build_message::<AZGroupsRef>(az_groups_id).call(|az_groups| {
+                az_groups.group_tags_update(0, vec!["dao".to_string(), "art".to_string()])
+            });
+            let result = client
+                .call_dry_run(&ink_e2e::alice(), &group_tags_update, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, Err(AZGroupsError::NotFound("Tag".to_string())));
+            // when the tags are in the vocabulary
+            let group_tags_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_tags_update(0, vec!["dao".to_string()]));
+            let result = client
+                .call(&ink_e2e::alice(), group_tags_update, 0, None)
+                .await
+                .expect("group_tags_update failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert!(matches!(
+                &events[..],
+                [Event::GroupTagsUpdate(GroupTagsUpdate { group_id: 0, tags })] if *tags == vec!["dao".to_string()]
+            ));
+
+            // tag_groups_index
+            let tag_groups_index = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.tag_groups_index("dao".to_string(), 0, 10));
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &tag_groups_index, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, vec![0]);
+
+            // tags_destroy
+            // * it keeps the tag on groups that already have it
+            let tags_destroy = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.tags_destroy("dao".to_string()));
+            client
+                .call(&ink_e2e::alice(), tags_destroy, 0, None)
+                .await
+                .expect("tags_destroy failed");
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &tags_show, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, Err(AZGroupsError::NotFound("Tag".to_string())));
+            let group_tags_index = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_tags_index(0));
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &group_tags_index, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, vec!["dao".to_string()]);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_group_slugs(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let az_groups_id = client
+                .instantiate("az_groups", &ink_e2e::alice(), AZGroupsRef::new(), 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let groups_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_create("The Next Wave".to_string()));
+            client
+                .call(&ink_e2e::alice(), groups_create, 0, None)
+                .await
+                .expect("groups_create failed");
+
+            // group_slugs_show
+            let group_slugs_show = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_slugs_show(0));
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &group_slugs_show, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, Ok("the-next-wave".to_string()));
+
+            // group_slugs_update
+            // when slug is invalid
+            let group_slugs_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_slugs_update(0, "Wave!".to_string()));
+            let result = client
+                .call_dry_run(&ink_e2e::alice(), &group_slugs_update, 0, None)
+                .await
+                .return_value();
+            assert!(matches!(result, Err(AZGroupsError::UnprocessableEntity(_))));
+            // when slug is valid
+            let group_slugs_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_slugs_update(0, "wave".to_string()));
+            let result = client
+                .call(&ink_e2e::alice(), group_slugs_update, 0, None)
+                .await
+                .expect("group_slugs_update failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert!(matches!(
+                &events[..],
+                [Event::GroupSlugUpdate(GroupSlugUpdate { group_id: 0, slug })] if slug == "wave"
+            ));
+
+            // groups_find_by_slug
+            let groups_find_by_slug = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_find_by_slug("wave".to_string()));
+            let group: Group = client
+                .call_dry_run(&ink_e2e::bob(), &groups_find_by_slug, 0, None)
+                .await
+                .return_value()
+                .unwrap();
+            assert_eq!(group.id, 0);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_group_name_rules(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let az_groups_id = client
+                .instantiate("az_groups", &ink_e2e::alice(), AZGroupsRef::new(), 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+
+            // group_name_rules_update
+            // when caller is not the owner
+            let group_name_rules_update =
+                build_message::<AZGroupsRef>(az_groups_id).call(|az_groups| {
+                    az_groups.group_name_rules_update(32, 16, GroupNameCharset::Ascii)
+                });
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &group_name_rules_update, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is the owner
+            client
+                .call(&ink_e2e::alice(), group_name_rules_update, 0, None)
+                .await
+                .expect("group_name_rules_update failed");
+
+            // group_name_rules_show
+            let group_name_rules_show = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_name_rules_show());
+            let result: GroupNameRules = client
+                .call_dry_run(&ink_e2e::bob(), &group_name_rules_show, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result.max_chars, 16);
+            assert_eq!(result.charset, GroupNameCharset::Ascii);
+
+            // groups_create
+            // when name breaks the name rules
+            for (name, message) in [
+                ("The Next Wave Foundation", "Name has too many characters"),
+                ("Th\u{00E9} Wave", "Name contains invalid characters"),
+            ] {
+                let groups_create = build_message::<AZGroupsRef>(az_groups_id)
+                    .call(|az_groups| az_groups.groups_create(name.to_string()));
+                let result = client
+                    .call_dry_run(&ink_e2e::bob(), &groups_create, 0, None)
+                    .await
+                    .return_value();
+                assert_eq!(
+                    result,
+                    Err(AZGroupsError::UnprocessableEntity(message.to_string()))
+                );
+            }
+
+            // reserved_names_create
+            let reserved_names_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.reserved_names_create("Aleph Zero".to_string()));
+            client
+                .call(&ink_e2e::alice(), reserved_names_create, 0, None)
+                .await
+                .expect("reserved_names_create failed");
+
+            // reserved_names_show
+            // * it matches lookalikes
+            let reserved_names_show = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.reserved_names_show("ALEPH ZER0".to_string()));
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &reserved_names_show, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, Ok("Aleph Zero".to_string()));
+            let groups_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_create("ALEPH ZER0".to_string()));
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &groups_create, 0, None)
+                .await
+                .return_value();
             assert_eq!(
                 result,
-                Err(AZGroupsError::NotFound("GroupUser".to_string()))
+                Err(AZGroupsError::UnprocessableEntity(
+                    "Name is reserved".to_string()
+                ))
             );
-            // == when GroupUser exists
-            // === when GroupUser is a member, admin or super admin
-            // === * it returns the role number
-            az_groups
-                .group_users
-                .insert((0, accounts.bob), &GroupUser { role: Role::Member });
-            result = az_groups.validate_membership(0, accounts.bob);
-            assert_eq!(result.unwrap(), Role::Member);
-            // === when GroupUser is banned or applicant
-            // === * it raises an error
-            az_groups.group_users.insert(
-                (0, accounts.bob),
-                &GroupUser {
-                    role: Role::Applicant,
-                },
+
+            // reserved_names_destroy
+            let reserved_names_destroy = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.reserved_names_destroy("Aleph Zero".to_string()));
+            client
+                .call(&ink_e2e::alice(), reserved_names_destroy, 0, None)
+                .await
+                .expect("reserved_names_destroy failed");
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &reserved_names_show, 0, None)
+                .await
+                .return_value();
+            assert_eq!(
+                result,
+                Err(AZGroupsError::NotFound("ReservedName".to_string()))
             );
-            result = az_groups.validate_membership(0, accounts.bob);
-            assert_eq!(result, Err(AZGroupsError::NotAMember));
-            // = when group is disabled
-            group.enabled = false;
-            az_groups.groups.insert(0, &group);
-            // = * it raises an error
-            result = az_groups.validate_membership(0, accounts.bob);
-            assert_eq!(result, Err(AZGroupsError::GroupDisabled));
+
+            // group_name_cooldowns_update
+            let group_name_cooldowns_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_name_cooldowns_update(86_400_000));
+            client
+                .call(&ink_e2e::alice(), group_name_cooldowns_update, 0, None)
+                .await
+                .expect("group_name_cooldowns_update failed");
+            let group_name_cooldowns_show = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_name_cooldowns_show());
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &group_name_cooldowns_show, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, 86_400_000);
+
+            // group_name_histories_index
+            // * it records the previous name, which still finds the group during the cooldown
+            let groups_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_create("The Next Wave".to_string()));
+            client
+                .call(&ink_e2e::bob(), groups_create, 0, None)
+                .await
+                .expect("groups_create failed");
+            let groups_update = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_update(0, "King Kong".to_string(), true));
+            client
+                .call(&ink_e2e::bob(), groups_update, 0, None)
+                .await
+                .expect("groups_update failed");
+            let group_name_histories_index = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_name_histories_index(0));
+            let result: Vec<GroupNameChange> = client
+                .call_dry_run(&ink_e2e::charlie(), &group_name_histories_index, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result.len(), 1);
+            assert_eq!(result[0].name, "The Next Wave");
+            let groups_find_by_name = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_find_by_name("The Next Wave".to_string()));
+            let lookup: GroupNameLookup = client
+                .call_dry_run(&ink_e2e::charlie(), &groups_find_by_name, 0, None)
+                .await
+                .return_value()
+                .unwrap();
+            assert_eq!(lookup.group.name, "King Kong");
+            assert!(lookup.redirected);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn e2e_verifiers(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let az_groups_id = client
+                .instantiate("az_groups", &ink_e2e::alice(), AZGroupsRef::new(), 0, None)
+                .await
+                .expect("instantiate failed")
+                .account_id;
+            let alice: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+            let charlie: AccountId = ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie);
+            let groups_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.groups_create("The Next Wave".to_string()));
+            client
+                .call(&ink_e2e::alice(), groups_create, 0, None)
+                .await
+                .expect("groups_create failed");
+
+            // verifiers_create
+            let verifiers_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.verifiers_create(charlie));
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &verifiers_create, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            client
+                .call(&ink_e2e::alice(), verifiers_create, 0, None)
+                .await
+                .expect("verifiers_create failed");
+
+            // verifiers_show
+            let verifiers_show = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.verifiers_show(charlie));
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &verifiers_show, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, Ok(charlie));
+
+            // group_verifications_create
+            // when caller is not a verifier
+            let group_verifications_create =
+                build_message::<AZGroupsRef>(az_groups_id).call(|az_groups| {
+                    az_groups.group_verifications_create(0, Hash::from([1; 32]), Timestamp::MAX)
+                });
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &group_verifications_create, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, Err(AZGroupsError::Unauthorised));
+            // when caller is a verifier
+            let result = client
+                .call(&ink_e2e::charlie(), group_verifications_create, 0, None)
+                .await
+                .expect("group_verifications_create failed");
+            let events: Vec<Event> = contract_events(&result.events);
+            assert!(matches!(
+                &events[..],
+                [Event::GroupVerificationCreate(GroupVerificationCreate { group_id: 0, verifier, .. })]
+                    if *verifier == charlie
+            ));
+            let validate_verified_membership = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.validate_verified_membership(0, alice));
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &validate_verified_membership, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, Ok(Role::SuperAdmin));
+
+            // verifiers_destroy
+            // * it hides the verifier's verifications
+            let verifiers_destroy = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.verifiers_destroy(charlie));
+            client
+                .call(&ink_e2e::alice(), verifiers_destroy, 0, None)
+                .await
+                .expect("verifiers_destroy failed");
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &validate_verified_membership, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, Err(AZGroupsError::GroupUnverified));
+            // = when the verifier is added back
+            // = * it shows the verification again
+            let verifiers_create = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.verifiers_create(charlie));
+            client
+                .call(&ink_e2e::alice(), verifiers_create, 0, None)
+                .await
+                .expect("verifiers_create failed");
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &validate_verified_membership, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, Ok(Role::SuperAdmin));
+
+            // group_verifications_destroy
+            let group_verifications_destroy = build_message::<AZGroupsRef>(az_groups_id)
+                .call(|az_groups| az_groups.group_verifications_destroy(0));
+            client
+                .call(&ink_e2e::charlie(), group_verifications_destroy, 0, None)
+                .await
+                .expect("group_verifications_destroy failed");
+            let result = client
+                .call_dry_run(&ink_e2e::bob(), &validate_verified_membership, 0, None)
+                .await
+                .return_value();
+            assert_eq!(result, Err(AZGroupsError::GroupUnverified));
+
+            Ok(())
         }
     }
 }
@@ -0,0 +1,323 @@
+// In memory stand-in for AZGroups, for consumer contracts to use in their off-chain tests.
+// The off-chain environment doesn't support cross contract calls, so consumers call the mock
+// registered at the AZGroups address instead of the contract when testing e.g.
+//
+// #[cfg(test)]
+// fn validate_membership(&self, group_id: u32, user: AccountId) -> Result<Role, AZGroupsError> {
+//     az_groups::mock::validate_membership(self.az_groups_address, group_id, user)
+// }
+use crate::{
+    az_groups::{Group, GroupNameLookup, GroupUser, GroupVerification, Role},
+    errors::AZGroupsError,
+    names::group_name_key,
+};
+use ink::{
+    env::DefaultEnvironment,
+    prelude::string::{String, ToString},
+    primitives::AccountId,
+};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+};
+
+thread_local! {
+    static MOCKS: RefCell<BTreeMap<AccountId, MockAZGroups>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MockAZGroups {
+    groups: BTreeMap<u32, Group>,
+    group_users: BTreeMap<(u32, AccountId), GroupUser>,
+    verifiers: BTreeSet<AccountId>,
+}
+impl MockAZGroups {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_group(mut self, id: u32, name: &str, enabled: bool) -> Self {
+        self.groups.insert(
+            id,
+            Group {
+                id,
+                name: name.to_string(),
+                enabled,
+                domain: None,
+                verification: None,
+            },
+        );
+        self
+    }
+
+    // Like the contract, a verification only counts while it hasn't expired and its verifier is registered.
+    // Panics if the group hasn't been added.
+    pub fn with_group_verification(mut self, id: u32, verification: GroupVerification) -> Self {
+        self.groups
+            .get_mut(&id)
+            .expect("no group with id")
+            .verification = Some(verification);
+        self
+    }
+
+    pub fn with_group_user(mut self, group_id: u32, user: AccountId, role: Role) -> Self {
+        self.group_users
+            .insert((group_id, user), GroupUser { role, weight: 1 });
+        self
+    }
+
+    pub fn with_verifier(mut self, verifier: AccountId) -> Self {
+        self.verifiers.insert(verifier);
+        self
+    }
+
+    pub fn without_verifier(mut self, verifier: AccountId) -> Self {
+        self.verifiers.remove(&verifier);
+        self
+    }
+
+    pub fn group_users_show(
+        &self,
+        group_id: u32,
+        user: AccountId,
+    ) -> Result<GroupUser, AZGroupsError> {
+        self.group_users
+            .get(&(group_id, user))
+            .cloned()
+            .ok_or(AZGroupsError::NotFound("GroupUser".to_string()))
+    }
+
+    pub fn groups_find_by_name(&self, name: String) -> Result<GroupNameLookup, AZGroupsError> {
+        self.groups
+            .values()
+            .find(|group| group_name_key(&group.name) == group_name_key(&name))
+            .map(|group| GroupNameLookup {
+                group: group.clone(),
+                redirected: false,
+            })
+            .ok_or(AZGroupsError::NotFound("Group".to_string()))
+    }
+
+    pub fn groups_show(&self, id: u32) -> Result<Group, AZGroupsError> {
+        let mut group: Group = self
+            .groups
+            .get(&id)
+            .cloned()
+            .ok_or(AZGroupsError::NotFound("Group".to_string()))?;
+        group.verification = group.verification.filter(|verification| {
+            verification.expires_at > ink::env::block_timestamp::<DefaultEnvironment>()
+                && self.verifiers.contains(&verification.verifier)
+        });
+
+        Ok(group)
+    }
+
+    pub fn validate_membership(
+        &self,
+        group_id: u32,
+        user: AccountId,
+    ) -> Result<Role, AZGroupsError> {
+        let group: Group = self.groups_show(group_id)?;
+        if !group.enabled {
+            return Err(AZGroupsError::GroupDisabled);
+        }
+        let group_user: GroupUser = self.group_users_show(group_id, user)?;
+        if group_user.role.to_int() < 2 {
+            return Err(AZGroupsError::NotAMember);
+        }
+
+        Ok(group_user.role)
+    }
+
+    pub fn validate_verified_membership(
+        &self,
+        group_id: u32,
+        user: AccountId,
+    ) -> Result<Role, AZGroupsError> {
+        if self.groups_show(group_id)?.verification.is_none() {
+            return Err(AZGroupsError::GroupUnverified);
+        }
+
+        self.validate_membership(group_id, user)
+    }
+}
+
+// Registers the mock at the address and marks the address as a contract in the off-chain environment.
+pub fn register(address: AccountId, mock: MockAZGroups) {
+    ink::env::test::set_contract::<DefaultEnvironment>(address);
+    MOCKS.with(|mocks| mocks.borrow_mut().insert(address, mock));
+}
+
+// Runs the closure against the mock registered at the address e.g. to change roles mid test.
+// Panics if no mock has been registered at the address.
+pub fn with_mock<R>(address: AccountId, f: impl FnOnce(&mut MockAZGroups) -> R) -> R {
+    MOCKS.with(|mocks| {
+        let mut mocks = mocks.borrow_mut();
+        let mock: &mut MockAZGroups = mocks
+            .get_mut(&address)
+            .expect("no MockAZGroups registered at address");
+        f(mock)
+    })
+}
+
+pub fn group_users_show(
+    address: AccountId,
+    group_id: u32,
+    user: AccountId,
+) -> Result<GroupUser, AZGroupsError> {
+    with_mock(address, |mock| mock.group_users_show(group_id, user))
+}
+
+pub fn groups_find_by_name(
+    address: AccountId,
+    name: String,
+) -> Result<GroupNameLookup, AZGroupsError> {
+    with_mock(address, |mock| mock.groups_find_by_name(name))
+}
+
+pub fn groups_show(address: AccountId, id: u32) -> Result<Group, AZGroupsError> {
+    with_mock(address, |mock| mock.groups_show(id))
+}
+
+pub fn validate_membership(
+    address: AccountId,
+    group_id: u32,
+    user: AccountId,
+) -> Result<Role, AZGroupsError> {
+    with_mock(address, |mock| mock.validate_membership(group_id, user))
+}
+
+pub fn validate_verified_membership(
+    address: AccountId,
+    group_id: u32,
+    user: AccountId,
+) -> Result<Role, AZGroupsError> {
+    with_mock(address, |mock| {
+        mock.validate_verified_membership(group_id, user)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[ink::test]
+    fn test_register() {
+        let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+        // * it marks the address as a contract
+        register(accounts.django, MockAZGroups::new());
+        assert!(ink::env::test::is_contract::<DefaultEnvironment>(
+            accounts.django
+        ));
+        // * it makes the mock available at the address
+        with_mock(accounts.django, |mock| {
+            *mock = mock.clone().with_group(0, "Aleph Zero Foundation", true)
+        });
+        assert_eq!(groups_show(accounts.django, 0).unwrap().id, 0);
+    }
+
+    #[ink::test]
+    fn test_groups_find_by_name() {
+        let mock = MockAZGroups::new().with_group(0, "Aleph Zero Foundation", true);
+        // when group with name does not exist
+        // * it raises an error
+        assert_eq!(
+            mock.groups_find_by_name("asdf".to_string()),
+            Err(AZGroupsError::NotFound("Group".to_string()))
+        );
+        // when group with name exists (case insensitive)
+        // * it returns the group
+        assert_eq!(
+            mock.groups_find_by_name(" ALEPH ZERO FOUNDATION".to_string())
+                .unwrap()
+                .group
+                .id,
+            0
+        );
+        // when name matches the group's name after normalization
+        // * it returns the group, as the contract does
+        assert_eq!(
+            mock.groups_find_by_name("\u{0410}leph Zer0 \u{FF26}oundation".to_string())
+                .unwrap()
+                .group
+                .id,
+            0
+        );
+    }
+
+    #[ink::test]
+    fn test_validate_membership() {
+        let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+        let mut mock = MockAZGroups::new();
+        // when group does not exist
+        // * it raises an error
+        let mut result = mock.validate_membership(0, accounts.bob);
+        assert_eq!(result, Err(AZGroupsError::NotFound("Group".to_string())));
+        // when group exists
+        mock = mock.with_group(0, "Aleph Zero Foundation", true);
+        // = when group user does not exist
+        // = * it raises an error
+        result = mock.validate_membership(0, accounts.bob);
+        assert_eq!(
+            result,
+            Err(AZGroupsError::NotFound("GroupUser".to_string()))
+        );
+        // = when group user is an applicant
+        // = * it raises an error
+        mock = mock.with_group_user(0, accounts.bob, Role::Applicant);
+        result = mock.validate_membership(0, accounts.bob);
+        assert_eq!(result, Err(AZGroupsError::NotAMember));
+        // = when group user is a member
+        // = * it returns the role
+        mock = mock.with_group_user(0, accounts.bob, Role::Admin);
+        result = mock.validate_membership(0, accounts.bob);
+        assert_eq!(result, Ok(Role::Admin));
+        // when group is disabled
+        // * it raises an error
+        mock = mock.with_group(0, "Aleph Zero Foundation", false);
+        result = mock.validate_membership(0, accounts.bob);
+        assert_eq!(result, Err(AZGroupsError::GroupDisabled));
+    }
+
+    #[ink::test]
+    fn test_validate_verified_membership() {
+        let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+        let mut mock = MockAZGroups::new()
+            .with_group(0, "Aleph Zero Foundation", true)
+            .with_group_user(0, accounts.bob, Role::Member);
+        // when group is not verified
+        // * it raises an error
+        let mut result = mock.validate_verified_membership(0, accounts.bob);
+        assert_eq!(result, Err(AZGroupsError::GroupUnverified));
+        // when group is verified
+        mock = mock
+            .with_verifier(accounts.django)
+            .with_group_verification(
+                0,
+                GroupVerification {
+                    verifier: accounts.django,
+                    evidence_hash: [0; 32].into(),
+                    expires_at: 1,
+                },
+            );
+        // * it validates the membership
+        result = mock.validate_verified_membership(0, accounts.bob);
+        assert_eq!(result, Ok(Role::Member));
+        result = mock.validate_verified_membership(0, accounts.charlie);
+        assert_eq!(
+            result,
+            Err(AZGroupsError::NotFound("GroupUser".to_string()))
+        );
+        // when verification has expired
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(1);
+        // * it raises an error
+        result = mock.validate_verified_membership(0, accounts.bob);
+        assert_eq!(result, Err(AZGroupsError::GroupUnverified));
+        // when verifier has been removed
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(0);
+        mock = mock.without_verifier(accounts.django);
+        // * it raises an error
+        result = mock.validate_verified_membership(0, accounts.bob);
+        assert_eq!(result, Err(AZGroupsError::GroupUnverified));
+    }
+}
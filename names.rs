@@ -0,0 +1,243 @@
+// Group name normalization.
+// The full Unicode normalization and confusables tables make the contract too large to deploy,
+// so only the compatibility forms, accented letters and lookalikes that are in common use are covered.
+use ink::prelude::{string::String, vec::Vec};
+
+// Mathematical, letterlike and other compatibility forms of ASCII letters and digits.
+#[rustfmt::skip]
+const COMPATIBILITY_FORMS: [(char, &str); 50] = [
+    ('\u{00AA}', "a"), ('\u{00B2}', "2"), ('\u{00B3}', "3"), ('\u{00B9}', "1"), ('\u{00BA}', "o"),
+    ('\u{0132}', "IJ"), ('\u{0133}', "ij"), ('\u{017F}', "s"), ('\u{2070}', "0"), ('\u{2071}', "i"),
+    ('\u{207F}', "n"), ('\u{2102}', "C"), ('\u{210A}', "g"), ('\u{210B}', "H"), ('\u{210C}', "H"),
+    ('\u{210D}', "H"), ('\u{210E}', "h"), ('\u{2110}', "I"), ('\u{2111}', "I"), ('\u{2112}', "L"),
+    ('\u{2113}', "l"), ('\u{2115}', "N"), ('\u{2119}', "P"), ('\u{211A}', "Q"), ('\u{211B}', "R"),
+    ('\u{211C}', "R"), ('\u{211D}', "R"), ('\u{2124}', "Z"), ('\u{2128}', "Z"), ('\u{212A}', "K"),
+    ('\u{212B}', "\u{00C5}"), ('\u{212C}', "B"), ('\u{212D}', "C"), ('\u{212F}', "e"), ('\u{2130}', "E"),
+    ('\u{2131}', "F"), ('\u{2133}', "M"), ('\u{2134}', "o"), ('\u{2139}', "i"), ('\u{2145}', "D"),
+    ('\u{2146}', "d"), ('\u{2147}', "e"), ('\u{2148}', "i"), ('\u{2149}', "j"), ('\u{FB00}', "ff"),
+    ('\u{FB01}', "fi"), ('\u{FB02}', "fl"), ('\u{FB03}', "ffi"), ('\u{FB04}', "ffl"), ('\u{FB05}', "st"),
+];
+
+// Latin-1 and Latin Extended-A letters that decompose into an ASCII letter and a combining mark.
+#[rustfmt::skip]
+const DECOMPOSITIONS: [(char, char, char); 161] = [
+    ('\u{00C0}', 'A', '\u{0300}'), ('\u{00C1}', 'A', '\u{0301}'), ('\u{00C2}', 'A', '\u{0302}'),
+    ('\u{00C3}', 'A', '\u{0303}'), ('\u{00C4}', 'A', '\u{0308}'), ('\u{00C5}', 'A', '\u{030A}'),
+    ('\u{00C7}', 'C', '\u{0327}'), ('\u{00C8}', 'E', '\u{0300}'), ('\u{00C9}', 'E', '\u{0301}'),
+    ('\u{00CA}', 'E', '\u{0302}'), ('\u{00CB}', 'E', '\u{0308}'), ('\u{00CC}', 'I', '\u{0300}'),
+    ('\u{00CD}', 'I', '\u{0301}'), ('\u{00CE}', 'I', '\u{0302}'), ('\u{00CF}', 'I', '\u{0308}'),
+    ('\u{00D1}', 'N', '\u{0303}'), ('\u{00D2}', 'O', '\u{0300}'), ('\u{00D3}', 'O', '\u{0301}'),
+    ('\u{00D4}', 'O', '\u{0302}'), ('\u{00D5}', 'O', '\u{0303}'), ('\u{00D6}', 'O', '\u{0308}'),
+    ('\u{00D9}', 'U', '\u{0300}'), ('\u{00DA}', 'U', '\u{0301}'), ('\u{00DB}', 'U', '\u{0302}'),
+    ('\u{00DC}', 'U', '\u{0308}'), ('\u{00DD}', 'Y', '\u{0301}'), ('\u{00E0}', 'a', '\u{0300}'),
+    ('\u{00E1}', 'a', '\u{0301}'), ('\u{00E2}', 'a', '\u{0302}'), ('\u{00E3}', 'a', '\u{0303}'),
+    ('\u{00E4}', 'a', '\u{0308}'), ('\u{00E5}', 'a', '\u{030A}'), ('\u{00E7}', 'c', '\u{0327}'),
+    ('\u{00E8}', 'e', '\u{0300}'), ('\u{00E9}', 'e', '\u{0301}'), ('\u{00EA}', 'e', '\u{0302}'),
+    ('\u{00EB}', 'e', '\u{0308}'), ('\u{00EC}', 'i', '\u{0300}'), ('\u{00ED}', 'i', '\u{0301}'),
+    ('\u{00EE}', 'i', '\u{0302}'), ('\u{00EF}', 'i', '\u{0308}'), ('\u{00F1}', 'n', '\u{0303}'),
+    ('\u{00F2}', 'o', '\u{0300}'), ('\u{00F3}', 'o', '\u{0301}'), ('\u{00F4}', 'o', '\u{0302}'),
+    ('\u{00F5}', 'o', '\u{0303}'), ('\u{00F6}', 'o', '\u{0308}'), ('\u{00F9}', 'u', '\u{0300}'),
+    ('\u{00FA}', 'u', '\u{0301}'), ('\u{00FB}', 'u', '\u{0302}'), ('\u{00FC}', 'u', '\u{0308}'),
+    ('\u{00FD}', 'y', '\u{0301}'), ('\u{00FF}', 'y', '\u{0308}'), ('\u{0100}', 'A', '\u{0304}'),
+    ('\u{0101}', 'a', '\u{0304}'), ('\u{0102}', 'A', '\u{0306}'), ('\u{0103}', 'a', '\u{0306}'),
+    ('\u{0104}', 'A', '\u{0328}'), ('\u{0105}', 'a', '\u{0328}'), ('\u{0106}', 'C', '\u{0301}'),
+    ('\u{0107}', 'c', '\u{0301}'), ('\u{0108}', 'C', '\u{0302}'), ('\u{0109}', 'c', '\u{0302}'),
+    ('\u{010A}', 'C', '\u{0307}'), ('\u{010B}', 'c', '\u{0307}'), ('\u{010C}', 'C', '\u{030C}'),
+    ('\u{010D}', 'c', '\u{030C}'), ('\u{010E}', 'D', '\u{030C}'), ('\u{010F}', 'd', '\u{030C}'),
+    ('\u{0112}', 'E', '\u{0304}'), ('\u{0113}', 'e', '\u{0304}'), ('\u{0114}', 'E', '\u{0306}'),
+    ('\u{0115}', 'e', '\u{0306}'), ('\u{0116}', 'E', '\u{0307}'), ('\u{0117}', 'e', '\u{0307}'),
+    ('\u{0118}', 'E', '\u{0328}'), ('\u{0119}', 'e', '\u{0328}'), ('\u{011A}', 'E', '\u{030C}'),
+    ('\u{011B}', 'e', '\u{030C}'), ('\u{011C}', 'G', '\u{0302}'), ('\u{011D}', 'g', '\u{0302}'),
+    ('\u{011E}', 'G', '\u{0306}'), ('\u{011F}', 'g', '\u{0306}'), ('\u{0120}', 'G', '\u{0307}'),
+    ('\u{0121}', 'g', '\u{0307}'), ('\u{0122}', 'G', '\u{0327}'), ('\u{0123}', 'g', '\u{0327}'),
+    ('\u{0124}', 'H', '\u{0302}'), ('\u{0125}', 'h', '\u{0302}'), ('\u{0128}', 'I', '\u{0303}'),
+    ('\u{0129}', 'i', '\u{0303}'), ('\u{012A}', 'I', '\u{0304}'), ('\u{012B}', 'i', '\u{0304}'),
+    ('\u{012C}', 'I', '\u{0306}'), ('\u{012D}', 'i', '\u{0306}'), ('\u{012E}', 'I', '\u{0328}'),
+    ('\u{012F}', 'i', '\u{0328}'), ('\u{0130}', 'I', '\u{0307}'), ('\u{0134}', 'J', '\u{0302}'),
+    ('\u{0135}', 'j', '\u{0302}'), ('\u{0136}', 'K', '\u{0327}'), ('\u{0137}', 'k', '\u{0327}'),
+    ('\u{0139}', 'L', '\u{0301}'), ('\u{013A}', 'l', '\u{0301}'), ('\u{013B}', 'L', '\u{0327}'),
+    ('\u{013C}', 'l', '\u{0327}'), ('\u{013D}', 'L', '\u{030C}'), ('\u{013E}', 'l', '\u{030C}'),
+    ('\u{0143}', 'N', '\u{0301}'), ('\u{0144}', 'n', '\u{0301}'), ('\u{0145}', 'N', '\u{0327}'),
+    ('\u{0146}', 'n', '\u{0327}'), ('\u{0147}', 'N', '\u{030C}'), ('\u{0148}', 'n', '\u{030C}'),
+    ('\u{014C}', 'O', '\u{0304}'), ('\u{014D}', 'o', '\u{0304}'), ('\u{014E}', 'O', '\u{0306}'),
+    ('\u{014F}', 'o', '\u{0306}'), ('\u{0150}', 'O', '\u{030B}'), ('\u{0151}', 'o', '\u{030B}'),
+    ('\u{0154}', 'R', '\u{0301}'), ('\u{0155}', 'r', '\u{0301}'), ('\u{0156}', 'R', '\u{0327}'),
+    ('\u{0157}', 'r', '\u{0327}'), ('\u{0158}', 'R', '\u{030C}'), ('\u{0159}', 'r', '\u{030C}'),
+    ('\u{015A}', 'S', '\u{0301}'), ('\u{015B}', 's', '\u{0301}'), ('\u{015C}', 'S', '\u{0302}'),
+    ('\u{015D}', 's', '\u{0302}'), ('\u{015E}', 'S', '\u{0327}'), ('\u{015F}', 's', '\u{0327}'),
+    ('\u{0160}', 'S', '\u{030C}'), ('\u{0161}', 's', '\u{030C}'), ('\u{0162}', 'T', '\u{0327}'),
+    ('\u{0163}', 't', '\u{0327}'), ('\u{0164}', 'T', '\u{030C}'), ('\u{0165}', 't', '\u{030C}'),
+    ('\u{0168}', 'U', '\u{0303}'), ('\u{0169}', 'u', '\u{0303}'), ('\u{016A}', 'U', '\u{0304}'),
+    ('\u{016B}', 'u', '\u{0304}'), ('\u{016C}', 'U', '\u{0306}'), ('\u{016D}', 'u', '\u{0306}'),
+    ('\u{016E}', 'U', '\u{030A}'), ('\u{016F}', 'u', '\u{030A}'), ('\u{0170}', 'U', '\u{030B}'),
+    ('\u{0171}', 'u', '\u{030B}'), ('\u{0172}', 'U', '\u{0328}'), ('\u{0173}', 'u', '\u{0328}'),
+    ('\u{0174}', 'W', '\u{0302}'), ('\u{0175}', 'w', '\u{0302}'), ('\u{0176}', 'Y', '\u{0302}'),
+    ('\u{0177}', 'y', '\u{0302}'), ('\u{0178}', 'Y', '\u{0308}'), ('\u{0179}', 'Z', '\u{0301}'),
+    ('\u{017A}', 'z', '\u{0301}'), ('\u{017B}', 'Z', '\u{0307}'), ('\u{017C}', 'z', '\u{0307}'),
+    ('\u{017D}', 'Z', '\u{030C}'), ('\u{017E}', 'z', '\u{030C}'),
+];
+
+// Letters from other scripts that look like an ASCII letter.
+// Uppercase and lowercase are listed separately, as some characters only look Latin in one case
+// e.g. Cyrillic "Т" looks like "T" but "т" doesn't look like "t".
+#[rustfmt::skip]
+const CONFUSABLES: [(char, char); 74] = [
+    // Greek
+    ('\u{0391}', 'A'), ('\u{0392}', 'B'), ('\u{0395}', 'E'), ('\u{0396}', 'Z'), ('\u{0397}', 'H'),
+    ('\u{0399}', 'l'), ('\u{039A}', 'K'), ('\u{039C}', 'M'), ('\u{039D}', 'N'), ('\u{039F}', 'O'),
+    ('\u{03A1}', 'P'), ('\u{03A4}', 'T'), ('\u{03A5}', 'Y'), ('\u{03A7}', 'X'), ('\u{03B1}', 'a'),
+    ('\u{03B9}', 'i'), ('\u{03BD}', 'v'), ('\u{03BF}', 'o'), ('\u{03C1}', 'p'), ('\u{03C5}', 'u'),
+    // Cyrillic
+    ('\u{0405}', 'S'), ('\u{0406}', 'l'), ('\u{0408}', 'J'), ('\u{0410}', 'A'), ('\u{0412}', 'B'),
+    ('\u{0415}', 'E'), ('\u{041A}', 'K'), ('\u{041C}', 'M'), ('\u{041D}', 'H'), ('\u{041E}', 'O'),
+    ('\u{0420}', 'P'), ('\u{0421}', 'C'), ('\u{0422}', 'T'), ('\u{0425}', 'X'), ('\u{0430}', 'a'),
+    ('\u{0435}', 'e'), ('\u{043E}', 'o'), ('\u{0440}', 'p'), ('\u{0441}', 'c'), ('\u{0443}', 'y'),
+    ('\u{0445}', 'x'), ('\u{0455}', 's'), ('\u{0456}', 'i'), ('\u{0458}', 'j'), ('\u{04AE}', 'Y'),
+    ('\u{04BB}', 'h'), ('\u{04C0}', 'l'), ('\u{04CF}', 'l'), ('\u{0501}', 'd'), ('\u{051A}', 'Q'),
+    ('\u{051B}', 'q'), ('\u{051C}', 'W'), ('\u{051D}', 'w'),
+    // Armenian
+    ('\u{0570}', 'h'), ('\u{0578}', 'n'), ('\u{057D}', 'u'), ('\u{0585}', 'o'),
+    // Latin
+    ('\u{0131}', 'i'), ('\u{0237}', 'j'), ('\u{0251}', 'a'), ('\u{0261}', 'g'), ('\u{026A}', 'i'),
+    ('\u{1D00}', 'a'), ('\u{1D04}', 'c'), ('\u{1D0F}', 'o'), ('\u{1D1C}', 'u'), ('\u{1D20}', 'v'),
+    ('\u{1D21}', 'w'), ('\u{1D22}', 'z'), ('\u{A731}', 's'), ('\u{AB83}', 'w'),
+    // Cherokee
+    ('\u{13A0}', 'D'), ('\u{13A1}', 'R'), ('\u{13A2}', 'T'),
+];
+
+// Compatibility forms are folded, whitespace is collapsed to single spaces and invisible characters are removed
+pub fn format_group_name(name: String) -> String {
+    let mut folded: String = String::with_capacity(name.len());
+    for c in name.chars() {
+        compatibility_fold(c, &mut folded);
+    }
+    folded
+        .chars()
+        .map(|c| if c.is_whitespace() { ' ' } else { c })
+        .filter(|c| !is_invisible_char(*c))
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+// The key used for uniqueness and lookups.
+// Accented letters are decomposed, so that precomposed and combining forms share a key,
+// and lookalikes are folded to the ASCII letter they look like e.g. Cyrillic "а" and Latin "a".
+// Lookalikes are matched before lowercasing, then ASCII lookalikes are folded after lowercasing
+// so that case doesn't matter e.g. "0" and "o", "I" and "i" and "l", or "M" and "m" and "rn".
+pub fn group_name_key(name: &str) -> String {
+    let mut key: String = String::new();
+    for c in format_group_name(name.to_string()).chars() {
+        let (base, mark): (char, Option<char>) = decompose(c);
+        let folded: String = match confusable(base) {
+            Some(ascii) => ascii.to_ascii_lowercase().to_string(),
+            None => base
+                .to_lowercase()
+                .map(|lowercase| confusable(lowercase).unwrap_or(lowercase))
+                .collect(),
+        };
+        for c in folded.chars() {
+            match c {
+                '0' => key.push('o'),
+                '1' | 'i' | '|' => key.push('l'),
+                'm' => key.push_str("rn"),
+                '`' => key.push('\''),
+                '"' => key.push_str("''"),
+                _ => key.push(c),
+            }
+        }
+        if let Some(mark) = mark {
+            key.push(mark);
+        }
+    }
+    key
+}
+
+// The letter without its accent e.g. "é" => "e".
+pub fn strip_accent(c: char) -> char {
+    decompose(c).0
+}
+
+// Combining diacritical marks, as used to write accents separately from the letter.
+pub fn is_combining_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{0483}'..='\u{0489}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+// Control characters and default ignorable code points e.g. zero-width spaces and joiners,
+// bidi overrides, variation selectors and tags.
+pub fn is_invisible_char(c: char) -> bool {
+    c.is_control()
+        || matches!(
+            c,
+            '\u{00AD}'
+                | '\u{034F}'
+                | '\u{061C}'
+                | '\u{115F}'..='\u{1160}'
+                | '\u{17B4}'..='\u{17B5}'
+                | '\u{180B}'..='\u{180F}'
+                | '\u{200B}'..='\u{200F}'
+                | '\u{202A}'..='\u{202E}'
+                | '\u{2060}'..='\u{206F}'
+                | '\u{3164}'
+                | '\u{FE00}'..='\u{FE0F}'
+                | '\u{FEFF}'
+                | '\u{FFA0}'
+                | '\u{FFF0}'..='\u{FFF8}'
+                | '\u{1BCA0}'..='\u{1BCA3}'
+                | '\u{1D173}'..='\u{1D17A}'
+                | '\u{E0000}'..='\u{E0FFF}'
+        )
+}
+
+fn compatibility_fold(c: char, folded: &mut String) {
+    let code: u32 = c as u32;
+    let offset_char =
+        |first: char, offset: u32| -> char { char::from_u32(first as u32 + offset).unwrap_or(c) };
+    match c {
+        // Fullwidth ASCII and the ideographic space
+        '\u{FF01}'..='\u{FF5E}' => folded.push(offset_char('!', code - 0xFF01)),
+        '\u{3000}' => folded.push(' '),
+        // Mathematical letters e.g. bold, italic, script and double-struck, 52 per style
+        '\u{1D400}'..='\u{1D6A3}' => {
+            let letter: u32 = (code - 0x1D400) % 52;
+            if letter < 26 {
+                folded.push(offset_char('A', letter))
+            } else {
+                folded.push(offset_char('a', letter - 26))
+            }
+        }
+        // Mathematical digits, 10 per style
+        '\u{1D7CE}'..='\u{1D7FF}' => folded.push(offset_char('0', (code - 0x1D7CE) % 10)),
+        // Superscript and subscript digits
+        '\u{2074}'..='\u{2079}' => folded.push(offset_char('4', code - 0x2074)),
+        '\u{2080}'..='\u{2089}' => folded.push(offset_char('0', code - 0x2080)),
+        _ => match COMPATIBILITY_FORMS.iter().find(|(form, _)| *form == c) {
+            Some((_, value)) => folded.push_str(value),
+            None => folded.push(c),
+        },
+    }
+}
+
+fn confusable(c: char) -> Option<char> {
+    CONFUSABLES
+        .iter()
+        .find(|(confusable, _)| *confusable == c)
+        .map(|(_, ascii)| *ascii)
+}
+
+fn decompose(c: char) -> (char, Option<char>) {
+    DECOMPOSITIONS
+        .iter()
+        .find(|(composed, _, _)| *composed == c)
+        .map(|(_, base, mark)| (*base, Some(*mark)))
+        .unwrap_or((c, None))
+}
@@ -0,0 +1,53 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "ink-as-dependency")]
+pub use crate::allowlist_join_condition::AllowlistJoinConditionRef;
+
+// Sample join condition that only lets accounts on an allowlist apply to a group.
+#[ink::contract]
+mod allowlist_join_condition {
+    use az_groups::traits::JoinCondition;
+    use ink::{prelude::vec::Vec, storage::Mapping};
+
+    #[ink(storage)]
+    pub struct AllowlistJoinCondition {
+        allowlist: Mapping<(u32, AccountId), ()>,
+    }
+    impl AllowlistJoinCondition {
+        #[ink(constructor)]
+        pub fn new(group_id: u32, accounts: Vec<AccountId>) -> Self {
+            let mut allowlist = Mapping::default();
+            for account in accounts {
+                allowlist.insert((group_id, account), &());
+            }
+            Self { allowlist }
+        }
+    }
+
+    impl JoinCondition for AllowlistJoinCondition {
+        #[ink(message)]
+        fn check(&self, group_id: u32, applicant: AccountId) -> bool {
+            self.allowlist.contains((group_id, applicant))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[ink::test]
+        fn test_check() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let allowlist_join_condition = AllowlistJoinCondition::new(0, vec![accounts.bob]);
+            // when applicant is on the allowlist for the group
+            // * it returns true
+            assert!(allowlist_join_condition.check(0, accounts.bob));
+            // when applicant is on the allowlist for a different group
+            // * it returns false
+            assert!(!allowlist_join_condition.check(1, accounts.bob));
+            // when applicant is not on the allowlist
+            // * it returns false
+            assert!(!allowlist_join_condition.check(0, accounts.charlie));
+        }
+    }
+}
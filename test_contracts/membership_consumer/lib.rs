@@ -0,0 +1,90 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "ink-as-dependency")]
+pub use crate::membership_consumer::MembershipConsumerRef;
+
+// Sample contract that integrates with AZGroups through validate_membership.
+#[ink::contract]
+mod membership_consumer {
+    use az_groups::{AZGroupsError, Role};
+
+    #[ink(storage)]
+    pub struct MembershipConsumer {
+        az_groups_address: AccountId,
+    }
+    impl MembershipConsumer {
+        #[ink(constructor)]
+        pub fn new(az_groups_address: AccountId) -> Self {
+            Self { az_groups_address }
+        }
+
+        // Returns the caller's role, as long as they are a member of the group and it's enabled.
+        #[ink(message)]
+        pub fn caller_role(&self, group_id: u32) -> Result<Role, AZGroupsError> {
+            self.validate_membership(group_id, Self::env().caller())
+        }
+
+        #[cfg(not(test))]
+        fn validate_membership(
+            &self,
+            group_id: u32,
+            user: AccountId,
+        ) -> Result<Role, AZGroupsError> {
+            use az_groups::AZGroupsInterface;
+
+            let az_groups: ink::contract_ref!(AZGroupsInterface) = self.az_groups_address.into();
+            az_groups.validate_membership(group_id, user)
+        }
+
+        #[cfg(test)]
+        fn validate_membership(
+            &self,
+            group_id: u32,
+            user: AccountId,
+        ) -> Result<Role, AZGroupsError> {
+            az_groups::mock::validate_membership(self.az_groups_address, group_id, user)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use az_groups::mock::{self, MockAZGroups};
+
+        #[ink::test]
+        fn test_caller_role() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            mock::register(
+                accounts.django,
+                MockAZGroups::new()
+                    .with_group(0, "Aleph Zero Foundation", true)
+                    .with_group_user(0, accounts.bob, Role::Member)
+                    .with_group_user(0, accounts.charlie, Role::Applicant),
+            );
+            let membership_consumer = MembershipConsumer::new(accounts.django);
+            // when caller is a member of the group
+            // * it returns the caller's role
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(membership_consumer.caller_role(0), Ok(Role::Member));
+            // when caller is an applicant
+            // * it raises an error
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                membership_consumer.caller_role(0),
+                Err(AZGroupsError::NotAMember)
+            );
+            // when group is disabled
+            // * it raises an error
+            mock::with_mock(accounts.django, |az_groups| {
+                *az_groups = az_groups
+                    .clone()
+                    .with_group(0, "Aleph Zero Foundation", false)
+            });
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                membership_consumer.caller_role(0),
+                Err(AZGroupsError::GroupDisabled)
+            );
+        }
+    }
+}
@@ -0,0 +1,71 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "ink-as-dependency")]
+pub use crate::mock_domain_registry::MockDomainRegistryRef;
+
+// Sample domain registry that lets anyone register an unowned domain, standing in for AZERO.ID.
+#[ink::contract]
+mod mock_domain_registry {
+    use az_groups::traits::DomainRegistry;
+    use ink::{prelude::string::String, storage::Mapping};
+
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct MockDomainRegistry {
+        owners: Mapping<String, AccountId>,
+    }
+    impl MockDomainRegistry {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        // Returns false if the domain is already owned.
+        #[ink(message)]
+        pub fn register(&mut self, domain: String) -> bool {
+            if self.owners.contains(domain.clone()) {
+                return false;
+            }
+
+            self.owners.insert(domain, &Self::env().caller());
+            true
+        }
+    }
+
+    impl DomainRegistry for MockDomainRegistry {
+        #[ink(message)]
+        fn get_owner(&self, domain: String) -> Option<AccountId> {
+            self.owners.get(domain)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[ink::test]
+        fn test_register() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut mock_domain_registry = MockDomainRegistry::new();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            // when domain isn't registered
+            // * it returns None
+            assert_eq!(mock_domain_registry.get_owner("wave.azero".into()), None);
+            // when domain is registered
+            // * it returns the owner
+            assert!(mock_domain_registry.register("wave.azero".into()));
+            assert_eq!(
+                mock_domain_registry.get_owner("wave.azero".into()),
+                Some(accounts.bob)
+            );
+            // when domain is already owned
+            // * it doesn't change the owner
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert!(!mock_domain_registry.register("wave.azero".into()));
+            assert_eq!(
+                mock_domain_registry.get_owner("wave.azero".into()),
+                Some(accounts.bob)
+            );
+        }
+    }
+}
@@ -0,0 +1,580 @@
+use crate::{
+    az_groups::{
+        Election, Group, GroupGovernance, GroupImpeachment, GroupJoinCondition, GroupMetadata,
+        GroupNameChange, GroupNameCharset, GroupNameLookup, GroupNameRules, GroupRecovery,
+        GroupRole, GroupUser, GroupUserProfile, JoinConditionPolicy, PendingOperation,
+        PendingPromotion, Petition, PetitionAction, Poll, Proposal, ProposalAction, Role,
+        SuperAdminClaim,
+    },
+    errors::AZGroupsError,
+};
+use ink::{
+    env::{DefaultEnvironment, Environment},
+    prelude::string::String,
+    prelude::vec::Vec,
+    primitives::{AccountId, Hash},
+};
+
+type Balance = <DefaultEnvironment as Environment>::Balance;
+type Timestamp = <DefaultEnvironment as Environment>::Timestamp;
+
+// The public messages of AZGroups.
+// Selectors are set explicitly so that they stay the same as when the messages were inherent,
+// i.e. consumers calling with `ink::selector_bytes!("validate_membership")` aren't affected.
+#[ink::trait_definition]
+pub trait AZGroupsInterface {
+    #[ink(message, selector = 0x34354C6E)]
+    fn domain_registries_show(&self) -> Option<AccountId>;
+
+    #[ink(message, selector = 0xB67AEB5B)]
+    fn domain_registries_update(
+        &mut self,
+        registry: Option<AccountId>,
+    ) -> Result<Option<AccountId>, AZGroupsError>;
+
+    #[ink(message, selector = 0x47EC76C5)]
+    fn election_nominations_create(
+        &mut self,
+        election_id: u32,
+        candidate: AccountId,
+    ) -> Result<Election, AZGroupsError>;
+
+    #[ink(message, selector = 0xC7BC6458)]
+    fn election_votes_create(
+        &mut self,
+        election_id: u32,
+        candidate: AccountId,
+    ) -> Result<Election, AZGroupsError>;
+
+    #[ink(message, selector = 0x03841EB9)]
+    fn elections_close(&mut self, id: u32) -> Result<Election, AZGroupsError>;
+
+    #[ink(message, selector = 0xF2C7DFE5)]
+    fn elections_create(
+        &mut self,
+        group_id: u32,
+        seats: u8,
+        nomination_period: Timestamp,
+        voting_period: Timestamp,
+        term: Timestamp,
+    ) -> Result<Election, AZGroupsError>;
+
+    #[ink(message, selector = 0xD7C3C8ED)]
+    fn elections_show(&self, id: u32) -> Result<Election, AZGroupsError>;
+
+    #[ink(message, selector = 0xE0A490A3)]
+    fn group_domains_destroy(&mut self, group_id: u32) -> Result<(), AZGroupsError>;
+
+    #[ink(message, selector = 0x582D096B)]
+    fn group_domains_update(
+        &mut self,
+        group_id: u32,
+        domain: String,
+        owner: Option<AccountId>,
+    ) -> Result<Group, AZGroupsError>;
+
+    #[ink(message, selector = 0x31721216)]
+    fn group_governances_show(&self, group_id: u32) -> Result<GroupGovernance, AZGroupsError>;
+
+    #[ink(message, selector = 0xC6F467D9)]
+    fn group_governances_update(
+        &mut self,
+        group_id: u32,
+        quorum: u32,
+        threshold: u8,
+        voting_period: Timestamp,
+    ) -> Result<GroupGovernance, AZGroupsError>;
+
+    #[ink(message, selector = 0xFD19D2C8)]
+    fn group_impeachments_show(&self, group_id: u32) -> Result<GroupImpeachment, AZGroupsError>;
+
+    #[ink(message, selector = 0xA1149818)]
+    fn group_impeachments_update(
+        &mut self,
+        group_id: u32,
+        admin_threshold: u8,
+        super_admin_threshold: u8,
+        window: Timestamp,
+    ) -> Result<GroupImpeachment, AZGroupsError>;
+
+    #[ink(message, selector = 0x0CDF537D)]
+    fn group_join_conditions_destroy(&mut self, group_id: u32) -> Result<(), AZGroupsError>;
+
+    #[ink(message, selector = 0x6F5413F7)]
+    fn group_join_conditions_show(
+        &self,
+        group_id: u32,
+    ) -> Result<GroupJoinCondition, AZGroupsError>;
+
+    #[ink(message, selector = 0x2971878F)]
+    fn group_join_conditions_update(
+        &mut self,
+        group_id: u32,
+        address: AccountId,
+        policy: JoinConditionPolicy,
+    ) -> Result<GroupJoinCondition, AZGroupsError>;
+
+    #[ink(message, selector = 0x4F2EA53E)]
+    fn group_metadata_show(&self, group_id: u32) -> Result<GroupMetadata, AZGroupsError>;
+
+    #[ink(message, selector = 0xDF9BF3FF)]
+    fn group_metadata_update(
+        &mut self,
+        group_id: u32,
+        metadata: GroupMetadata,
+    ) -> Result<GroupMetadata, AZGroupsError>;
+
+    #[ink(message, selector = 0xF1A7B3EF)]
+    fn group_multisigs_show(&self, group_id: u32) -> u8;
+
+    #[ink(message, selector = 0xAAFD9F67)]
+    fn group_multisigs_update(
+        &mut self,
+        group_id: u32,
+        confirmations: u8,
+    ) -> Result<u8, AZGroupsError>;
+
+    #[ink(message, selector = 0xEB12419A)]
+    fn group_name_cooldowns_show(&self) -> Timestamp;
+
+    #[ink(message, selector = 0x6F4A5EA5)]
+    fn group_name_cooldowns_update(
+        &mut self,
+        cooldown: Timestamp,
+    ) -> Result<Timestamp, AZGroupsError>;
+
+    #[ink(message, selector = 0xC608097E)]
+    fn group_name_histories_index(&self, group_id: u32) -> Vec<GroupNameChange>;
+
+    #[ink(message, selector = 0x554F62E3)]
+    fn group_name_rules_show(&self) -> GroupNameRules;
+
+    #[ink(message, selector = 0x9964BC5F)]
+    fn group_name_rules_update(
+        &mut self,
+        max_bytes: u32,
+        max_chars: u32,
+        charset: GroupNameCharset,
+    ) -> Result<GroupNameRules, AZGroupsError>;
+
+    #[ink(message, selector = 0x7BA7E510)]
+    fn group_observers_create(
+        &mut self,
+        group_id: u32,
+        observer: AccountId,
+    ) -> Result<Vec<AccountId>, AZGroupsError>;
+
+    #[ink(message, selector = 0x31409704)]
+    fn group_observers_destroy(
+        &mut self,
+        group_id: u32,
+        observer: AccountId,
+    ) -> Result<Vec<AccountId>, AZGroupsError>;
+
+    #[ink(message, selector = 0x75AB5A85)]
+    fn group_observers_index(&self, group_id: u32) -> Vec<AccountId>;
+
+    #[ink(message, selector = 0x27515FCB)]
+    fn group_promotion_delays_show(&self, group_id: u32) -> Timestamp;
+
+    #[ink(message, selector = 0x3D40D3C4)]
+    fn group_promotion_delays_update(
+        &mut self,
+        group_id: u32,
+        delay: Timestamp,
+    ) -> Result<Timestamp, AZGroupsError>;
+
+    #[ink(message, selector = 0xCFE4370A)]
+    fn group_recoveries_show(&self, group_id: u32) -> Result<GroupRecovery, AZGroupsError>;
+
+    #[ink(message, selector = 0xC4DD9873)]
+    fn group_recoveries_update(
+        &mut self,
+        group_id: u32,
+        inactivity_period: Timestamp,
+        challenge_period: Timestamp,
+    ) -> Result<GroupRecovery, AZGroupsError>;
+
+    #[ink(message, selector = 0x4A605E24)]
+    fn group_role_capabilities_show(&self, group_id: u32, role: Role) -> u32;
+
+    #[ink(message, selector = 0xB3E1EA5B)]
+    fn group_role_capabilities_update(
+        &mut self,
+        group_id: u32,
+        role: Role,
+        capabilities: u32,
+    ) -> Result<u32, AZGroupsError>;
+
+    #[ink(message, selector = 0x6C7F2789)]
+    fn group_roles_create(
+        &mut self,
+        group_id: u32,
+        name: String,
+        rank: u8,
+        base: Role,
+    ) -> Result<GroupRole, AZGroupsError>;
+
+    #[ink(message, selector = 0xF1FFFD37)]
+    fn group_roles_destroy(&mut self, group_id: u32, rank: u8) -> Result<(), AZGroupsError>;
+
+    #[ink(message, selector = 0xDF8EF055)]
+    fn group_roles_index(&self, group_id: u32) -> Vec<GroupRole>;
+
+    #[ink(message, selector = 0x93ADABA0)]
+    fn group_slugs_show(&self, group_id: u32) -> Result<String, AZGroupsError>;
+
+    #[ink(message, selector = 0x0EFE46FE)]
+    fn group_slugs_update(&mut self, group_id: u32, slug: String) -> Result<String, AZGroupsError>;
+
+    #[ink(message, selector = 0xF1562312)]
+    fn group_super_admin_activities_show(&self, group_id: u32) -> Timestamp;
+
+    #[ink(message, selector = 0x9C2E02BE)]
+    fn group_super_admin_activities_update(
+        &mut self,
+        group_id: u32,
+    ) -> Result<Timestamp, AZGroupsError>;
+
+    #[ink(message, selector = 0xBEC67411)]
+    fn group_tags_index(&self, group_id: u32) -> Vec<String>;
+
+    #[ink(message, selector = 0x3FAC506E)]
+    fn group_tags_update(
+        &mut self,
+        group_id: u32,
+        tags: Vec<String>,
+    ) -> Result<Vec<String>, AZGroupsError>;
+
+    #[ink(message, payable, selector = 0xACF5E871)]
+    fn group_treasuries_deposit(&mut self, group_id: u32) -> Result<Balance, AZGroupsError>;
+
+    #[ink(message, selector = 0xDFD2DA55)]
+    fn group_treasuries_show(&self, group_id: u32) -> Balance;
+
+    #[ink(message, selector = 0x89B3F117)]
+    fn group_user_capabilities_show(&self, group_id: u32, user: AccountId) -> u32;
+
+    #[ink(message, selector = 0x2CCE3881)]
+    fn group_user_capabilities_update(
+        &mut self,
+        group_id: u32,
+        user: AccountId,
+        capabilities: u32,
+    ) -> Result<u32, AZGroupsError>;
+
+    #[ink(message, selector = 0x4E9B0C0A)]
+    fn group_user_profiles_show(
+        &self,
+        group_id: u32,
+        user: AccountId,
+    ) -> Result<GroupUserProfile, AZGroupsError>;
+
+    #[ink(message, selector = 0x7CDC3C01)]
+    fn group_user_profiles_update(
+        &mut self,
+        group_id: u32,
+        display_name: Option<String>,
+    ) -> Result<GroupUserProfile, AZGroupsError>;
+
+    #[ink(message, selector = 0x1A9B3B30)]
+    fn group_user_roles_show(&self, group_id: u32, user: AccountId) -> Option<GroupRole>;
+
+    #[ink(message, selector = 0xC57F072E)]
+    fn group_user_roles_update(
+        &mut self,
+        group_id: u32,
+        user: AccountId,
+        rank: Option<u8>,
+    ) -> Result<Option<GroupRole>, AZGroupsError>;
+
+    #[ink(message, selector = 0xFF7810F0)]
+    fn group_user_terms_destroy(
+        &mut self,
+        group_id: u32,
+        user: AccountId,
+    ) -> Result<GroupUser, AZGroupsError>;
+
+    #[ink(message, selector = 0xF7B7B9A1)]
+    fn group_user_terms_show(
+        &self,
+        group_id: u32,
+        user: AccountId,
+    ) -> Result<Timestamp, AZGroupsError>;
+
+    #[ink(message, selector = 0xD9787108)]
+    fn group_user_titles_update(
+        &mut self,
+        group_id: u32,
+        user: AccountId,
+        title: Option<String>,
+    ) -> Result<GroupUserProfile, AZGroupsError>;
+
+    #[ink(message, selector = 0xB6BCDFFB)]
+    fn group_user_weights_update(
+        &mut self,
+        group_id: u32,
+        user: AccountId,
+        weight: u128,
+    ) -> Result<GroupUser, AZGroupsError>;
+
+    #[ink(message, selector = 0x5C2A7440)]
+    fn group_users_create(&mut self, group_id: u32) -> Result<GroupUser, AZGroupsError>;
+
+    #[ink(message, selector = 0xF626CE8F)]
+    fn group_users_destroy(&mut self, group_id: u32, user: AccountId) -> Result<(), AZGroupsError>;
+
+    #[ink(message, selector = 0x9F2F8D2B)]
+    fn group_users_show(&self, group_id: u32, user: AccountId) -> Result<GroupUser, AZGroupsError>;
+
+    #[ink(message, selector = 0x226C42AA)]
+    fn group_users_update(
+        &mut self,
+        group_id: u32,
+        user: AccountId,
+        role: Role,
+    ) -> Result<GroupUser, AZGroupsError>;
+
+    #[ink(message, selector = 0x3233424C)]
+    fn group_verifications_create(
+        &mut self,
+        group_id: u32,
+        evidence_hash: Hash,
+        expires_at: Timestamp,
+    ) -> Result<Group, AZGroupsError>;
+
+    #[ink(message, selector = 0x13093FFC)]
+    fn group_verifications_destroy(&mut self, group_id: u32) -> Result<(), AZGroupsError>;
+
+    #[ink(message, selector = 0x3D2D4901)]
+    fn group_vouch_thresholds_show(&self, group_id: u32) -> u8;
+
+    #[ink(message, selector = 0x4220D792)]
+    fn group_vouch_thresholds_update(
+        &mut self,
+        group_id: u32,
+        threshold: u8,
+    ) -> Result<u8, AZGroupsError>;
+
+    #[ink(message, selector = 0xD3643257)]
+    fn group_weight_limits_show(&self, group_id: u32) -> u128;
+
+    #[ink(message, selector = 0x2A2995E2)]
+    fn group_weight_limits_update(
+        &mut self,
+        group_id: u32,
+        limit: u128,
+    ) -> Result<u128, AZGroupsError>;
+
+    #[ink(message, selector = 0x75DAC0BA)]
+    fn group_weight_totals_show(&self, group_id: u32) -> u128;
+
+    #[ink(message, selector = 0x25F0C549)]
+    fn groups_create(&mut self, name: String) -> Result<Group, AZGroupsError>;
+
+    #[ink(message, selector = 0xAE6BC97D)]
+    fn groups_find_by_name(&self, name: String) -> Result<GroupNameLookup, AZGroupsError>;
+
+    #[ink(message, selector = 0xD6C62189)]
+    fn groups_find_by_slug(&self, slug: String) -> Result<Group, AZGroupsError>;
+
+    #[ink(message, selector = 0xB738A269)]
+    fn groups_show(&self, id: u32) -> Result<Group, AZGroupsError>;
+
+    #[ink(message, selector = 0x57BF3ED4)]
+    fn groups_update(
+        &mut self,
+        id: u32,
+        name: String,
+        enabled: bool,
+    ) -> Result<Group, AZGroupsError>;
+
+    #[ink(message, selector = 0x7ACE36FB)]
+    fn owner_show(&self) -> AccountId;
+
+    #[ink(message, selector = 0x78565546)]
+    fn pending_operations_confirm(&mut self, id: u32) -> Result<PendingOperation, AZGroupsError>;
+
+    #[ink(message, selector = 0x11E3808D)]
+    fn pending_operations_index(&self, group_id: u32) -> Vec<PendingOperation>;
+
+    #[ink(message, selector = 0x7B6DB3E1)]
+    fn pending_operations_revoke(&mut self, id: u32) -> Result<PendingOperation, AZGroupsError>;
+
+    #[ink(message, selector = 0x00A45EBF)]
+    fn pending_promotions_destroy(
+        &mut self,
+        group_id: u32,
+        user: AccountId,
+    ) -> Result<(), AZGroupsError>;
+
+    #[ink(message, selector = 0x4155F522)]
+    fn pending_promotions_execute(
+        &mut self,
+        group_id: u32,
+        user: AccountId,
+    ) -> Result<GroupUser, AZGroupsError>;
+
+    #[ink(message, selector = 0x263A3DF9)]
+    fn pending_promotions_index(&self, group_id: u32) -> Vec<PendingPromotion>;
+
+    #[ink(message, selector = 0x7DAE8A46)]
+    fn petition_signatures_create(&mut self, petition_id: u32) -> Result<Petition, AZGroupsError>;
+
+    #[ink(message, selector = 0x7896A9F0)]
+    fn petitions_create(
+        &mut self,
+        group_id: u32,
+        target: AccountId,
+        action: PetitionAction,
+    ) -> Result<Petition, AZGroupsError>;
+
+    #[ink(message, selector = 0xE081CB19)]
+    fn petitions_show(&self, id: u32) -> Result<Petition, AZGroupsError>;
+
+    #[ink(message, selector = 0xB803F2DD)]
+    fn poll_votes_create(&mut self, poll_id: u32, option: u8) -> Result<Poll, AZGroupsError>;
+
+    #[ink(message, selector = 0xB806413D)]
+    fn polls_create(
+        &mut self,
+        group_id: u32,
+        question_hash: Hash,
+        options: u8,
+        opens_at: Timestamp,
+        closes_at: Timestamp,
+    ) -> Result<Poll, AZGroupsError>;
+
+    #[ink(message, selector = 0x3329F0BA)]
+    fn polls_show(&self, id: u32) -> Result<Poll, AZGroupsError>;
+
+    #[ink(message, selector = 0x07806A41)]
+    fn proposal_votes_create(
+        &mut self,
+        proposal_id: u32,
+        support: bool,
+    ) -> Result<Proposal, AZGroupsError>;
+
+    #[ink(message, selector = 0x75DC432C)]
+    fn proposals_create(
+        &mut self,
+        group_id: u32,
+        description_hash: Hash,
+        action: ProposalAction,
+    ) -> Result<Proposal, AZGroupsError>;
+
+    #[ink(message, selector = 0x11DA0026)]
+    fn proposals_show(&self, id: u32) -> Result<Proposal, AZGroupsError>;
+
+    #[ink(message, selector = 0xBF7D8CD4)]
+    fn reserved_names_create(&mut self, name: String) -> Result<String, AZGroupsError>;
+
+    #[ink(message, selector = 0x55A49FE2)]
+    fn reserved_names_destroy(&mut self, name: String) -> Result<(), AZGroupsError>;
+
+    #[ink(message, selector = 0x912B969F)]
+    fn reserved_names_show(&self, name: String) -> Result<String, AZGroupsError>;
+
+    #[ink(message, selector = 0xD92BC4BE)]
+    fn super_admin_claim_supports_create(
+        &mut self,
+        group_id: u32,
+    ) -> Result<SuperAdminClaim, AZGroupsError>;
+
+    #[ink(message, selector = 0xF6FCF5FA)]
+    fn super_admin_claims_create(
+        &mut self,
+        group_id: u32,
+    ) -> Result<SuperAdminClaim, AZGroupsError>;
+
+    #[ink(message, selector = 0x172B36C4)]
+    fn super_admin_claims_destroy(&mut self, group_id: u32) -> Result<(), AZGroupsError>;
+
+    #[ink(message, selector = 0xE87E73D7)]
+    fn super_admin_claims_execute(&mut self, group_id: u32) -> Result<GroupUser, AZGroupsError>;
+
+    #[ink(message, selector = 0x227A270C)]
+    fn super_admin_claims_show(&self, group_id: u32) -> Result<SuperAdminClaim, AZGroupsError>;
+
+    #[ink(message, selector = 0xD66E9C8E)]
+    fn tag_groups_index(&self, tag: String, start: u32, limit: u32) -> Vec<u32>;
+
+    #[ink(message, selector = 0x3102E936)]
+    fn tags_create(&mut self, tag: String) -> Result<String, AZGroupsError>;
+
+    #[ink(message, selector = 0xDA7696D9)]
+    fn tags_destroy(&mut self, tag: String) -> Result<(), AZGroupsError>;
+
+    #[ink(message, selector = 0xC38E539D)]
+    fn tags_show(&self, tag: String) -> Result<String, AZGroupsError>;
+
+    #[ink(message, selector = 0xC5160381)]
+    fn validate_membership(&self, group_id: u32, user: AccountId) -> Result<Role, AZGroupsError>;
+
+    #[ink(message, selector = 0x368766ED)]
+    fn validate_verified_membership(
+        &self,
+        group_id: u32,
+        user: AccountId,
+    ) -> Result<Role, AZGroupsError>;
+
+    #[ink(message, selector = 0xBC2E3C20)]
+    fn verifiers_create(&mut self, verifier: AccountId) -> Result<AccountId, AZGroupsError>;
+
+    #[ink(message, selector = 0x24BDFB99)]
+    fn verifiers_destroy(&mut self, verifier: AccountId) -> Result<(), AZGroupsError>;
+
+    #[ink(message, selector = 0xE9C324C8)]
+    fn verifiers_show(&self, verifier: AccountId) -> Result<AccountId, AZGroupsError>;
+
+    #[ink(message, selector = 0x353DCB22)]
+    fn vouch(
+        &mut self,
+        group_id: u32,
+        applicant: AccountId,
+    ) -> Result<Vec<AccountId>, AZGroupsError>;
+
+    #[ink(message, selector = 0x5389BAF3)]
+    fn vouches_destroy(
+        &mut self,
+        group_id: u32,
+        applicant: AccountId,
+    ) -> Result<Vec<AccountId>, AZGroupsError>;
+
+    #[ink(message, selector = 0x707B6AF0)]
+    fn vouches_index(&self, group_id: u32, applicant: AccountId) -> Vec<AccountId>;
+}
+
+// The domain registry set by the contract owner has to implement this trait, e.g. an adapter for AZERO.ID.
+// Returns the owner of the domain, or None if it isn't registered.
+#[ink::trait_definition]
+pub trait DomainRegistry {
+    #[ink(message)]
+    fn get_owner(&self, domain: String) -> Option<AccountId>;
+}
+
+// Contracts implementing this trait can be attached to a group by a super admin.
+// The condition is checked whenever a user applies to join the group.
+#[ink::trait_definition]
+pub trait JoinCondition {
+    #[ink(message)]
+    fn check(&self, group_id: u32, applicant: AccountId) -> bool;
+}
+
+// Contracts implementing this trait can be registered on a group by a super admin.
+// They are called after every membership change and whenever the group is enabled or disabled.
+// Callbacks are best effort, a failing callback doesn't revert the change.
+#[ink::trait_definition]
+pub trait GroupObserver {
+    #[ink(message)]
+    fn on_group_update(&mut self, group_id: u32, enabled: bool);
+
+    #[ink(message)]
+    fn on_group_user_create(&mut self, group_id: u32, user: AccountId, role: Role);
+
+    #[ink(message)]
+    fn on_group_user_destroy(&mut self, group_id: u32, user: AccountId);
+
+    #[ink(message)]
+    fn on_group_user_update(&mut self, group_id: u32, user: AccountId, role: Role);
+}